@@ -44,20 +44,24 @@
 
 // up_core_api types used and augmented by up_rust - symbols re-exported to toplevel, errors are module-specific
 mod rpc;
-pub use rpc::{RpcClient, RpcClientResult, RpcResult};
+pub use rpc::{RpcClient, RpcClientResult, RpcMapper, RpcResult};
 
 mod uattributes;
 pub use uattributes::{
-    PublishValidator, RequestValidator, ResponseValidator, UAttributesValidator,
-    UAttributesValidators,
+    should_shed, PublishValidator, RequestValidator, ResponseValidator, Severity,
+    UAttributesValidator, UAttributesValidators, ValidationSummary, ValidatorRegistry,
+};
+pub use uattributes::{
+    FieldDiff, UAttributes, UAttributesError, UMessageType, UPayloadFormat, UPriority,
 };
-pub use uattributes::{UAttributes, UAttributesError, UMessageType, UPayloadFormat, UPriority};
 
 mod umessage;
-pub use umessage::{UMessage, UMessageBuilder, UMessageError};
+pub use umessage::{
+    SchemaRegistry, Sink, Source, UMessage, UMessageBuilder, UMessageError, UriAttribute,
+};
 
 mod uri;
-pub use uri::{UUri, UUriError};
+pub use uri::{UUri, UUriError, UriCache, UriRole};
 
 mod ustatus;
 pub use ustatus::{UCode, UStatus};
@@ -12,7 +12,9 @@
  ********************************************************************************/
 
 mod rpcclient;
+mod rpcmapper;
 mod rpcresult;
 
 pub use rpcclient::*;
+pub use rpcmapper::*;
 pub use rpcresult::*;
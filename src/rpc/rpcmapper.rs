@@ -0,0 +1,361 @@
+/********************************************************************************
+ * Copyright (c) 2024 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+use protobuf::Enum;
+
+use crate::{
+    RequestValidator, ResponseValidator, UAttributes, UAttributesError, UAttributesValidator,
+    UMessage, UUri,
+};
+
+/// Provides consistency checks for the messages exchanged during an RPC method invocation.
+///
+/// The [`crate::UAttributesValidator`]s check the attributes of a single message only. The functions
+/// of `RpcMapper` check the relation between the attributes of a request message and the attributes
+/// of the corresponding response message.
+pub struct RpcMapper;
+
+impl RpcMapper {
+    /// Verifies that the priority of a response message is not lower than the priority of the request.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The attributes of the request message.
+    /// * `response` - The attributes of the response message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the response's priority is lower than the request's priority
+    /// or if any of the priorities is not a valid [`crate::UPriority`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{RpcMapper, UAttributes, UPriority};
+    ///
+    /// let request = UAttributes {
+    ///     priority: UPriority::UPRIORITY_CS5.into(),
+    ///     ..Default::default()
+    /// };
+    /// let response = UAttributes {
+    ///     priority: UPriority::UPRIORITY_CS4.into(),
+    ///     ..Default::default()
+    /// };
+    /// assert!(RpcMapper::validate_priority_consistency(&request, &response).is_err());
+    /// assert!(RpcMapper::validate_priority_consistency(&request, &request).is_ok());
+    /// ```
+    pub fn validate_priority_consistency(
+        request: &UAttributes,
+        response: &UAttributes,
+    ) -> Result<(), UAttributesError> {
+        let request_priority = request.priority.enum_value().map_err(|unknown_code| {
+            UAttributesError::validation_error(format!(
+                "Request message has invalid priority [{}]",
+                unknown_code
+            ))
+        })?;
+        let response_priority = response.priority.enum_value().map_err(|unknown_code| {
+            UAttributesError::validation_error(format!(
+                "Response message has invalid priority [{}]",
+                unknown_code
+            ))
+        })?;
+        if response_priority.value() < request_priority.value() {
+            Err(UAttributesError::validation_error(format!(
+                "Response message's priority [{}] must not be lower than request message's priority [{}]",
+                response_priority.to_priority_code(),
+                request_priority.to_priority_code()
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Verifies that a request message and a response message form a well-formed RPC exchange.
+    ///
+    /// This is useful for checking a captured exchange of messages, e.g. in integration tests.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request message.
+    /// * `response` - The response message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error containing all problems that have been found, if
+    ///
+    /// * the request's attributes are not valid according to the [`RequestValidator`], or
+    /// * the response's attributes are not valid according to the [`ResponseValidator`], or
+    /// * the response's request ID does not match the request's message ID, or
+    /// * the response's source does not match the request's sink (the method to invoke), or
+    /// * the response's sink does not match the request's source (the reply-to address).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{RpcMapper, UMessageBuilder, UUri};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let method_to_invoke = UUri::try_from("//my-vehicle/4210/5/64AB")?;
+    /// let reply_to_address = UUri::try_from("//my-cloud/BA4C/1/0")?;
+    /// let request = UMessageBuilder::request(method_to_invoke, reply_to_address, 5000).build()?;
+    /// let response = UMessageBuilder::response_for_request(&request.attributes).build()?;
+    /// assert!(RpcMapper::validate_exchange(&request, &response).is_ok());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate_exchange(
+        request: &UMessage,
+        response: &UMessage,
+    ) -> Result<(), UAttributesError> {
+        let request_attributes = request.attributes.get_or_default();
+        let response_attributes = response.attributes.get_or_default();
+
+        let mut errors = vec![
+            RequestValidator.validate(request_attributes),
+            ResponseValidator.validate(response_attributes),
+        ]
+        .into_iter()
+        .filter_map(Result::err)
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>();
+
+        if response_attributes.reqid != request_attributes.id {
+            errors.push(
+                "Response message's request ID does not match request message's ID".to_string(),
+            );
+        }
+        if response_attributes.source != request_attributes.sink {
+            errors.push(
+                "Response message's source does not match request message's method to invoke"
+                    .to_string(),
+            );
+        }
+        if response_attributes.sink != request_attributes.source {
+            errors.push(
+                "Response message's sink does not match request message's reply-to address"
+                    .to_string(),
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(UAttributesError::validation_error(errors.join("; ")))
+        }
+    }
+
+    /// Checks if a response message has been sent by the method that a request message has invoked.
+    ///
+    /// The URIs are compared by means of their [canonical representation](`UUri::canonical_topic`),
+    /// i.e. URIs that only differ in the case of their authority name refer to the same method.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The attributes of the request message.
+    /// * `response` - The attributes of the response message.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the request's sink and the response's source refer to the same method,
+    /// `false` otherwise or if any of the URIs is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{RpcMapper, UMessageBuilder, UUri};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let method_to_invoke = UUri::try_from("//my-vehicle/4210/5/64AB")?;
+    /// let reply_to_address = UUri::try_from("//my-cloud/BA4C/1/0")?;
+    /// let request = UMessageBuilder::request(method_to_invoke, reply_to_address, 5000).build()?;
+    /// let response = UMessageBuilder::response_for_request(&request.attributes).build()?;
+    /// assert!(RpcMapper::same_method(&request.attributes, &response.attributes));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn same_method(request: &UAttributes, response: &UAttributes) -> bool {
+        match (request.sink.as_ref(), response.source.as_ref()) {
+            (Some(invoked_method), Some(responding_method)) => {
+                invoked_method.canonical_topic() == responding_method.canonical_topic()
+            }
+            _ => false,
+        }
+    }
+
+    /// Verifies that a response message can be routed back to the requester.
+    ///
+    /// Full reachability can only be determined by a transport. However, a response to a request
+    /// that originated from a remote uEntity must not be addressed to a local uEntity.
+    ///
+    /// # Arguments
+    ///
+    /// * `request_source` - The source (reply-to address) of the request message.
+    /// * `response_sink` - The sink of the response message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request source is a remote URI but the response sink is a local URI.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{RpcMapper, UUri};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let request_source = UUri::try_from("//my-cloud/A/1/0")?;
+    /// assert!(RpcMapper::validate_response_routing(&request_source, &request_source).is_ok());
+    ///
+    /// let local_sink = UUri::try_from("/A/1/0")?;
+    /// assert!(RpcMapper::validate_response_routing(&request_source, &local_sink).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate_response_routing(
+        request_source: &UUri,
+        response_sink: &UUri,
+    ) -> Result<(), UAttributesError> {
+        if !request_source.is_local() && response_sink.is_local() {
+            Err(UAttributesError::validation_error(format!(
+                "Response message to remote requester [{}] must not have local sink [{}]",
+                request_source.to_uri(true),
+                response_sink.to_uri(true)
+            )))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use protobuf::EnumOrUnknown;
+    use test_case::test_case;
+
+    use super::*;
+    use crate::{UMessageBuilder, UPriority, UUIDBuilder};
+
+    fn request_message() -> UMessage {
+        let method_to_invoke = UUri::try_from("//my-vehicle/4D123/2/6FA3").unwrap();
+        let reply_to_address = UUri::try_from("//my-cloud/9CB3/1/0").unwrap();
+        UMessageBuilder::request(method_to_invoke, reply_to_address, 5000)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_validate_exchange_succeeds_for_valid_exchange() {
+        let request = request_message();
+        let response = UMessageBuilder::response_for_request(&request.attributes)
+            .build()
+            .unwrap();
+        assert!(RpcMapper::validate_exchange(&request, &response).is_ok());
+    }
+
+    #[test]
+    fn test_validate_exchange_fails_for_broken_correlation() {
+        let request = request_message();
+        let response = UMessageBuilder::response(
+            request.attributes.source.get_or_default().clone(),
+            UUIDBuilder::build(),
+            request.attributes.sink.get_or_default().clone(),
+        )
+        .build()
+        .unwrap();
+        assert!(RpcMapper::validate_exchange(&request, &response).is_err());
+    }
+
+    #[test]
+    fn test_validate_exchange_fails_for_wrong_reply_to_address() {
+        let request = request_message();
+        let response = UMessageBuilder::response(
+            UUri::try_from("//other-cloud/9CB3/1/0").unwrap(),
+            request.attributes.id.get_or_default().clone(),
+            request.attributes.sink.get_or_default().clone(),
+        )
+        .build()
+        .unwrap();
+        assert!(RpcMapper::validate_exchange(&request, &response).is_err());
+    }
+
+    #[test_case(Some("//my-vehicle/4D123/2/6FA3"), true; "for same method")]
+    #[test_case(Some("//MY-VEHICLE/4D123/2/6FA3"), true; "for same method with different case")]
+    #[test_case(Some("//my-vehicle/4D123/2/6FA4"), false; "for different method")]
+    #[test_case(Some("//other-vehicle/4D123/2/6FA3"), false; "for method on other device")]
+    #[test_case(None, false; "for missing source")]
+    fn test_same_method(response_source: Option<&str>, expected_result: bool) {
+        let request = request_message();
+        let response = UAttributes {
+            source: response_source
+                .map(|uri| UUri::try_from(uri).unwrap())
+                .into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            RpcMapper::same_method(&request.attributes, &response),
+            expected_result
+        );
+    }
+
+    #[test_case("//my-cloud/A/1/0", "//my-cloud/A/1/0", true; "succeeds for remote source and remote sink")]
+    #[test_case("/A/1/0", "/A/1/0", true; "succeeds for local source and local sink")]
+    #[test_case("/A/1/0", "//my-cloud/A/1/0", true; "succeeds for local source and remote sink")]
+    #[test_case("//my-cloud/A/1/0", "/A/1/0", false; "fails for remote source and local sink")]
+    fn test_validate_response_routing(
+        request_source: &str,
+        response_sink: &str,
+        expected_result: bool,
+    ) {
+        let request_source = UUri::try_from(request_source).unwrap();
+        let response_sink = UUri::try_from(response_sink).unwrap();
+        assert!(
+            RpcMapper::validate_response_routing(&request_source, &response_sink).is_ok()
+                == expected_result
+        );
+    }
+
+    #[test_case(UPriority::UPRIORITY_CS4, UPriority::UPRIORITY_CS4, true; "succeeds for matching priorities")]
+    #[test_case(UPriority::UPRIORITY_CS4, UPriority::UPRIORITY_CS6, true; "succeeds for upgraded response")]
+    #[test_case(UPriority::UPRIORITY_CS5, UPriority::UPRIORITY_CS4, false; "fails for downgraded response")]
+    fn test_validate_priority_consistency(
+        request_priority: UPriority,
+        response_priority: UPriority,
+        expected_result: bool,
+    ) {
+        let request = UAttributes {
+            priority: request_priority.into(),
+            ..Default::default()
+        };
+        let response = UAttributes {
+            priority: response_priority.into(),
+            ..Default::default()
+        };
+        assert!(
+            RpcMapper::validate_priority_consistency(&request, &response).is_ok()
+                == expected_result
+        );
+    }
+
+    #[test]
+    fn test_validate_priority_consistency_fails_for_unknown_priority() {
+        let request = UAttributes {
+            priority: UPriority::UPRIORITY_CS4.into(),
+            ..Default::default()
+        };
+        let response = UAttributes {
+            priority: EnumOrUnknown::from_i32(42),
+            ..Default::default()
+        };
+        assert!(RpcMapper::validate_priority_consistency(&request, &response).is_err());
+    }
+}
@@ -11,6 +11,10 @@
  * SPDX-License-Identifier: Apache-2.0
  ********************************************************************************/
 
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use protobuf::{Enum, EnumOrUnknown, Message};
+
 use crate::{UUri, UUID};
 
 mod uattributesvalidator;
@@ -55,7 +59,54 @@ impl std::fmt::Display for UAttributesError {
 
 impl std::error::Error for UAttributesError {}
 
+/// A field that differs between two sets of attributes, see [`UAttributes::diff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// The name of the field.
+    pub field: &'static str,
+    /// The field's value in the attributes that [`UAttributes::diff`] has been invoked on.
+    pub old_value: String,
+    /// The field's value in the attributes that have been passed into [`UAttributes::diff`].
+    pub new_value: String,
+}
+
 impl UAttributes {
+    /// The number of milliseconds that [`UAttributes::derive_downstream_deadline`] reserves
+    /// for processing the response of a downstream call.
+    pub const DOWNSTREAM_DEADLINE_HEADROOM_MS: u32 = 50;
+
+    /// Creates attributes that have not been configured yet.
+    ///
+    /// The attributes need to be filled in before being used for a message, in particular the
+    /// message type. This is equivalent to [`UAttributes::default`] but makes explicit that the
+    /// attributes are intentionally empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{UAttributes, UAttributesValidators};
+    ///
+    /// let attributes = UAttributes::unspecified();
+    /// assert!(attributes.is_unspecified());
+    /// assert!(UAttributesValidators::try_get_validator_for_attributes(&attributes).is_err());
+    /// ```
+    pub fn unspecified() -> Self {
+        Self {
+            type_: UMessageType::UMESSAGE_TYPE_UNSPECIFIED.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Checks if these attributes have not been configured yet.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the message type is [`UMessageType::UMESSAGE_TYPE_UNSPECIFIED`]. Such attributes
+    /// cannot be validated and must not be used for sending a message.
+    pub fn is_unspecified(&self) -> bool {
+        self.type_.enum_value() == Ok(UMessageType::UMESSAGE_TYPE_UNSPECIFIED)
+    }
+
     /// Creates attributes representing an RPC request message.
     ///
     /// The message's priority will be set to [`UPriority::UPRIORITY_CS4`].
@@ -109,4 +160,453 @@ impl UAttributes {
             ..Default::default()
         }
     }
+
+    /// Checks if these attributes describe an RPC response to a given request.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The attributes of the request message.
+    ///
+    /// # Returns
+    ///
+    /// `true` if these attributes are of type [`UMessageType::UMESSAGE_TYPE_RESPONSE`],
+    /// their request ID matches the request's message ID and their sink matches the
+    /// request's source (the reply-to address).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{UAttributes, UMessageType, UUIDBuilder, UUri};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let method_to_invoke = UUri::try_from("//my-vehicle/1004F3B/3/B42")?;
+    /// let reply_to_address = UUri::try_from("//my-cloud/A/1/0")?;
+    /// let request = UAttributes::request(UUIDBuilder::build(), method_to_invoke.clone(), reply_to_address.clone(), None, None, Some(5_000));
+    /// let response = UAttributes {
+    ///     type_: UMessageType::UMESSAGE_TYPE_RESPONSE.into(),
+    ///     id: Some(UUIDBuilder::build()).into(),
+    ///     reqid: request.id.clone(),
+    ///     source: Some(method_to_invoke).into(),
+    ///     sink: Some(reply_to_address).into(),
+    ///     ..Default::default()
+    /// };
+    /// assert!(response.is_response_to(&request));
+    /// assert!(!request.is_response_to(&response));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_response_to(&self, request: &UAttributes) -> bool {
+        self.type_.enum_value_or_default() == UMessageType::UMESSAGE_TYPE_RESPONSE
+            && self.reqid.is_some()
+            && self.reqid == request.id
+            && self.sink.is_some()
+            && self.sink == request.source
+    }
+
+    /// Gets the number of bytes that these attributes occupy in their protobuf encoding.
+    ///
+    /// This is useful for transports that need to account for the overhead of message headers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UAttributes;
+    ///
+    /// let attributes = UAttributes {
+    ///     ttl: Some(5_000),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(UAttributes::default().encoded_len(), 0);
+    /// assert_eq!(attributes.encoded_len(), 3);
+    /// ```
+    pub fn encoded_len(&self) -> usize {
+        self.compute_size() as usize
+    }
+
+    /// Gets the number of bytes that each of these attributes' fields occupies in the protobuf encoding.
+    ///
+    /// This helps to find out which attribute contributes most to the overall size of the attributes.
+    /// Fields that are not set occupy 0 bytes. The sum of all field sizes equals [`UAttributes::encoded_len`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UAttributes;
+    ///
+    /// let attributes = UAttributes {
+    ///     token: Some("my-token".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let (name, len) = attributes
+    ///     .encoded_len_by_field()
+    ///     .into_iter()
+    ///     .max_by_key(|(_name, len)| *len)
+    ///     .unwrap();
+    /// assert_eq!(name, "token");
+    /// assert_eq!(len, attributes.encoded_len());
+    /// ```
+    pub fn encoded_len_by_field(&self) -> Vec<(&'static str, usize)> {
+        vec![
+            Self::encoded_field_len("id", |a| a.id = self.id.clone()),
+            Self::encoded_field_len("type_", |a| a.type_ = self.type_),
+            Self::encoded_field_len("source", |a| a.source = self.source.clone()),
+            Self::encoded_field_len("sink", |a| a.sink = self.sink.clone()),
+            Self::encoded_field_len("priority", |a| a.priority = self.priority),
+            Self::encoded_field_len("ttl", |a| a.ttl = self.ttl),
+            Self::encoded_field_len("permission_level", |a| {
+                a.permission_level = self.permission_level
+            }),
+            Self::encoded_field_len("commstatus", |a| a.commstatus = self.commstatus),
+            Self::encoded_field_len("reqid", |a| a.reqid = self.reqid.clone()),
+            Self::encoded_field_len("token", |a| a.token = self.token.clone()),
+            Self::encoded_field_len("traceparent", |a| a.traceparent = self.traceparent.clone()),
+            Self::encoded_field_len("payload_format", |a| a.payload_format = self.payload_format),
+        ]
+    }
+
+    fn encoded_field_len(
+        name: &'static str,
+        set_field: impl FnOnce(&mut UAttributes),
+    ) -> (&'static str, usize) {
+        let mut attributes = UAttributes::default();
+        set_field(&mut attributes);
+        (name, attributes.compute_size() as usize)
+    }
+
+    /// Determines the fields that differ between these and other attributes.
+    ///
+    /// This is useful for finding out why a message fails validation, e.g. by comparing its
+    /// attributes to those of a known-good message.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The attributes to compare to.
+    ///
+    /// # Returns
+    ///
+    /// The differing fields along with their values in a human readable form. Absent values are
+    /// represented by `-`. The value of the `token` field is never included verbatim but only
+    /// indicated as `<redacted>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{FieldDiff, UAttributes};
+    ///
+    /// let attributes = UAttributes {
+    ///     ttl: Some(5_000),
+    ///     ..Default::default()
+    /// };
+    /// let other_attributes = UAttributes {
+    ///     ttl: Some(100),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     attributes.diff(&other_attributes),
+    ///     vec![FieldDiff {
+    ///         field: "ttl",
+    ///         old_value: "5000".to_string(),
+    ///         new_value: "100".to_string(),
+    ///     }]
+    /// );
+    /// ```
+    pub fn diff(&self, other: &UAttributes) -> Vec<FieldDiff> {
+        self.field_values()
+            .into_iter()
+            .zip(other.field_values())
+            .filter(|((_field, old_value), (_other_field, new_value))| old_value != new_value)
+            .map(|((field, old_value), (_other_field, new_value))| {
+                let redact = |value: String| {
+                    if field == "token" && value != "-" {
+                        "<redacted>".to_string()
+                    } else {
+                        value
+                    }
+                };
+                FieldDiff {
+                    field,
+                    old_value: redact(old_value),
+                    new_value: redact(new_value),
+                }
+            })
+            .collect()
+    }
+
+    /// Determines the time-to-live to use for a downstream call made while processing the
+    /// message described by these attributes.
+    ///
+    /// The downstream call's time-to-live is the time remaining until this message expires
+    /// minus [`UAttributes::DOWNSTREAM_DEADLINE_HEADROOM_MS`]. This makes sure that the downstream
+    /// call does not outlive the original message.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current point in time.
+    ///
+    /// # Returns
+    ///
+    /// The time-to-live in milliseconds or `None`, if these attributes do not contain a message ID
+    /// with a creation time and a (positive) time-to-live, or if the time remaining until this message
+    /// expires does not exceed the headroom.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use up_rust::{UAttributes, UUri, UUIDBuilder};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let message_id = UUIDBuilder::build();
+    /// let creation_time = UNIX_EPOCH + Duration::from_millis(message_id.get_time().unwrap());
+    /// let method_to_invoke = UUri::try_from("//my-vehicle/1004F3B/3/B42")?;
+    /// let reply_to_address = UUri::try_from("//my-cloud/A/1/0")?;
+    /// let attributes = UAttributes::request(message_id, method_to_invoke, reply_to_address, None, None, Some(1_000));
+    ///
+    /// let now = creation_time + Duration::from_millis(400);
+    /// assert_eq!(
+    ///     attributes.derive_downstream_deadline(now),
+    ///     Some(600 - UAttributes::DOWNSTREAM_DEADLINE_HEADROOM_MS)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn derive_downstream_deadline(&self, now: SystemTime) -> Option<u32> {
+        let ttl = self.ttl.filter(|ttl| *ttl > 0)?;
+        let creation_time = self.id.as_ref().and_then(UUID::get_time)?;
+        let now_millis = now
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .and_then(|duration| u64::try_from(duration.as_millis()).ok())?;
+        let elapsed = now_millis.saturating_sub(creation_time);
+        u64::from(ttl)
+            .checked_sub(elapsed)
+            .and_then(|remaining| {
+                remaining.checked_sub(u64::from(Self::DOWNSTREAM_DEADLINE_HEADROOM_MS))
+            })
+            .filter(|downstream_ttl| *downstream_ttl > 0)
+            .map(|downstream_ttl| downstream_ttl as u32)
+    }
+
+    fn field_values(&self) -> Vec<(&'static str, String)> {
+        fn enum_name<E: Enum + std::fmt::Debug>(value: EnumOrUnknown<E>) -> String {
+            value
+                .enum_value()
+                .map_or_else(|code| code.to_string(), |e| format!("{:?}", e))
+        }
+        let absent = || "-".to_string();
+
+        vec![
+            (
+                "id",
+                self.id
+                    .as_ref()
+                    .map_or_else(absent, UUID::to_hyphenated_string),
+            ),
+            ("type_", enum_name(self.type_)),
+            (
+                "source",
+                self.source
+                    .as_ref()
+                    .map_or_else(absent, |uri| uri.to_uri(false)),
+            ),
+            (
+                "sink",
+                self.sink
+                    .as_ref()
+                    .map_or_else(absent, |uri| uri.to_uri(false)),
+            ),
+            ("priority", enum_name(self.priority)),
+            ("ttl", self.ttl.map_or_else(absent, |ttl| ttl.to_string())),
+            (
+                "permission_level",
+                self.permission_level
+                    .map_or_else(absent, |level| level.to_string()),
+            ),
+            ("commstatus", self.commstatus.map_or_else(absent, enum_name)),
+            (
+                "reqid",
+                self.reqid
+                    .as_ref()
+                    .map_or_else(absent, UUID::to_hyphenated_string),
+            ),
+            ("token", self.token.clone().unwrap_or_else(absent)),
+            (
+                "traceparent",
+                self.traceparent.clone().unwrap_or_else(absent),
+            ),
+            ("payload_format", enum_name(self.payload_format)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use test_case::test_case;
+
+    use super::*;
+    use crate::UUIDBuilder;
+
+    fn request_attributes() -> UAttributes {
+        UAttributes::request(
+            UUIDBuilder::build(),
+            UUri::try_from("//my-vehicle/4D123/2/6FA3").unwrap(),
+            UUri::try_from("//my-cloud/9CB3/1/0").unwrap(),
+            None,
+            None,
+            Some(5_000),
+        )
+    }
+
+    fn response_attributes(request: &UAttributes) -> UAttributes {
+        UAttributes {
+            type_: UMessageType::UMESSAGE_TYPE_RESPONSE.into(),
+            id: Some(UUIDBuilder::build()).into(),
+            priority: UPriority::UPRIORITY_CS4.into(),
+            reqid: request.id.clone(),
+            source: request.sink.clone(),
+            sink: request.source.clone(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_is_response_to_succeeds_for_matching_response() {
+        let request = request_attributes();
+        let response = response_attributes(&request);
+        assert!(response.is_response_to(&request));
+    }
+
+    #[test]
+    fn test_is_response_to_fails_for_mismatched_request_id() {
+        let request = request_attributes();
+        let mut response = response_attributes(&request);
+        response.reqid = Some(UUIDBuilder::build()).into();
+        assert!(!response.is_response_to(&request));
+    }
+
+    #[test]
+    fn test_is_response_to_fails_for_mismatched_sink() {
+        let request = request_attributes();
+        let mut response = response_attributes(&request);
+        response.sink = Some(UUri::try_from("//other-cloud/9CB3/1/0").unwrap()).into();
+        assert!(!response.is_response_to(&request));
+    }
+
+    #[test]
+    fn test_encoded_len_increases_by_token_length() {
+        let mut attributes = request_attributes();
+        let len_without_token = attributes.encoded_len();
+
+        let token = "my-rather-long-access-token";
+        attributes.token = Some(token.to_string());
+        let len_with_token = attributes.encoded_len();
+
+        // the token's bytes plus field tag and length prefix
+        assert_eq!(len_with_token - len_without_token, token.len() + 2);
+    }
+
+    #[test]
+    fn test_encoded_len_by_field_adds_up_to_encoded_len() {
+        let mut attributes = request_attributes();
+        attributes.token = Some("my-token".to_string());
+        attributes.permission_level = Some(5);
+
+        let field_lens = attributes.encoded_len_by_field();
+        assert_eq!(
+            field_lens.iter().map(|(_name, len)| len).sum::<usize>(),
+            attributes.encoded_len()
+        );
+        assert!(field_lens.contains(&("permission_level", 2)));
+    }
+
+    #[test]
+    fn test_diff_reports_differing_fields_only() {
+        let attributes = request_attributes();
+        let mut other_attributes = attributes.clone();
+        other_attributes.ttl = Some(100);
+        other_attributes.priority = UPriority::UPRIORITY_CS5.into();
+
+        assert_eq!(
+            attributes.diff(&other_attributes),
+            vec![
+                FieldDiff {
+                    field: "priority",
+                    old_value: "UPRIORITY_CS4".to_string(),
+                    new_value: "UPRIORITY_CS5".to_string(),
+                },
+                FieldDiff {
+                    field: "ttl",
+                    old_value: "5000".to_string(),
+                    new_value: "100".to_string(),
+                },
+            ]
+        );
+        assert!(attributes.diff(&attributes).is_empty());
+    }
+
+    #[test]
+    fn test_diff_redacts_token() {
+        let mut attributes = request_attributes();
+        attributes.token = Some("my-token".to_string());
+        let mut other_attributes = attributes.clone();
+        other_attributes.token = Some("my-other-token".to_string());
+
+        let diffs = attributes.diff(&other_attributes);
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].field, "token");
+        assert_eq!(diffs[0].old_value, "<redacted>");
+        assert_eq!(diffs[0].new_value, "<redacted>");
+
+        other_attributes.token = None;
+        assert_eq!(attributes.diff(&other_attributes)[0].new_value, "-");
+    }
+
+    #[test]
+    fn test_unspecified_attributes_fail_validation() {
+        let attributes = UAttributes::unspecified();
+        assert!(attributes.is_unspecified());
+        assert!(!request_attributes().is_unspecified());
+
+        let error = UAttributesValidators::try_get_validator_for_attributes(&attributes)
+            .err()
+            .expect("should not have found validator for unspecified attributes");
+        assert!(error.to_string().contains("Message type is unspecified"));
+    }
+
+    #[test_case(Some(1_000), 400, Some(550); "for remaining budget")]
+    #[test_case(Some(1_000), 0, Some(950); "for full budget")]
+    #[test_case(Some(1_000), 950, None; "for budget not exceeding headroom")]
+    #[test_case(Some(1_000), 1_200, None; "for expired message")]
+    #[test_case(Some(0), 0, None; "for message without expiry")]
+    #[test_case(None, 0, None; "for message without ttl")]
+    fn test_derive_downstream_deadline(
+        ttl: Option<u32>,
+        elapsed_millis: u64,
+        expected_ttl: Option<u32>,
+    ) {
+        let mut attributes = request_attributes();
+        attributes.ttl = ttl;
+        let creation_time = attributes.id.get_time().unwrap();
+        let now = UNIX_EPOCH + Duration::from_millis(creation_time + elapsed_millis);
+        assert_eq!(attributes.derive_downstream_deadline(now), expected_ttl);
+    }
+
+    #[test]
+    fn test_derive_downstream_deadline_fails_for_missing_id() {
+        let mut attributes = request_attributes();
+        attributes.id.clear();
+        assert!(attributes
+            .derive_downstream_deadline(SystemTime::now())
+            .is_none());
+    }
+
+    #[test]
+    fn test_is_response_to_fails_for_non_response_type() {
+        let request = request_attributes();
+        let mut response = response_attributes(&request);
+        response.type_ = UMessageType::UMESSAGE_TYPE_NOTIFICATION.into();
+        assert!(!response.is_response_to(&request));
+    }
 }
@@ -11,14 +11,59 @@
  * SPDX-License-Identifier: Apache-2.0
  ********************************************************************************/
 
+use std::any::Any;
+use std::collections::HashMap;
 use std::time::SystemTime;
 
 use protobuf::Enum;
 
-use crate::{UAttributes, UMessageType, UPriority, UUri, UUID};
+use crate::{UAttributes, UCode, UMessageType, UPriority, UUri, UUID};
 
 use crate::UAttributesError;
 
+/// The severity of a problem found when validating attributes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The attributes are invalid.
+    Error,
+    /// The attributes are valid but use a discouraged configuration.
+    Warning,
+}
+
+/// The number of problems of each severity found when validating attributes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationSummary {
+    /// The number of problems with [`Severity::Error`].
+    pub errors: usize,
+    /// The number of problems with [`Severity::Warning`].
+    pub warnings: usize,
+}
+
+impl ValidationSummary {
+    /// Checks if the validated attributes are valid, i.e. no errors have been found.
+    pub fn is_valid(&self) -> bool {
+        self.errors == 0
+    }
+}
+
+mod private {
+    use std::any::Any;
+
+    /// Gives access to a validator as [`Any`], backing `as_any` on validator trait objects.
+    ///
+    /// The trait is sealed and implemented for all validators, so implementors of
+    /// [`super::UAttributesValidator`] do not need to do anything.
+    pub trait AsAny {
+        fn any_ref(&self) -> &dyn Any;
+    }
+
+    impl<T: super::UAttributesValidator + 'static> AsAny for T {
+        fn any_ref(&self) -> &dyn Any {
+            self
+        }
+    }
+}
+
 /// `UAttributes` is the struct that defines the Payload. It serves as the configuration for various aspects
 /// like time to live, priority, security tokens, and more. Each variant of `UAttributes` defines a different
 /// type of message payload. The payload could represent a simple published payload with some state change,
@@ -26,7 +71,10 @@ use crate::UAttributesError;
 ///
 /// `UAttributesValidator` is a trait implemented by all validators for `UAttributes`. It provides functionality
 /// to help validate that a given `UAttributes` instance is correctly configured to define the Payload.
-pub trait UAttributesValidator: Send {
+///
+/// Validators are required to be `Send` and `Sync`, so boxed validators, e.g. as returned by
+/// [`UAttributesValidators::validator`], can safely be shared among multiple threads.
+pub trait UAttributesValidator: private::AsAny + Send + Sync {
     /// Checks if a given set of attributes complies with the rules specified for
     /// the type of message they describe.
     ///
@@ -35,6 +83,91 @@ pub trait UAttributesValidator: Send {
     /// Returns an error if the attributes are not consistent with the rules specified for the message type.
     fn validate(&self, attributes: &UAttributes) -> Result<(), UAttributesError>;
 
+    /// Checks if a given set of attributes complies with the rules specified for
+    /// the type of message they describe.
+    ///
+    /// The default implementation returns the error returned by [`UAttributesValidator::validate`], if any.
+    ///
+    /// # Returns
+    ///
+    /// All errors that have been found. The built-in validators perform the same checks as in
+    /// [`UAttributesValidator::validate`] but return each error individually.
+    ///
+    /// The built-in validators report errors in the order in which their checks are listed in the
+    /// documentation of their `validate` function, e.g. type, ID, TTL, source, sink and priority
+    /// for [`RequestValidator::validate`]. The errors joined into the message of the error returned
+    /// by `validate` are in the same order, so both representations are stable.
+    fn validation_errors(&self, attributes: &UAttributes) -> Vec<UAttributesError> {
+        self.validate(attributes).err().into_iter().collect()
+    }
+
+    /// Checks a given set of attributes for configurations that are valid but discouraged.
+    ///
+    /// The default implementation does not report any warnings.
+    ///
+    /// # Returns
+    ///
+    /// A description of each discouraged configuration that has been found.
+    fn warnings(&self, _attributes: &UAttributes) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Checks a given set of attributes and reports all problems that have been found.
+    ///
+    /// # Returns
+    ///
+    /// The [errors](`UAttributesValidator::validation_errors`) and [warnings](`UAttributesValidator::warnings`)
+    /// along with their severity.
+    fn issues(&self, attributes: &UAttributes) -> Vec<(Severity, String)> {
+        let mut issues: Vec<(Severity, String)> = self
+            .validation_errors(attributes)
+            .into_iter()
+            .map(|e| (Severity::Error, e.to_string()))
+            .collect();
+        issues.extend(
+            self.warnings(attributes)
+                .into_iter()
+                .map(|warning| (Severity::Warning, warning)),
+        );
+        issues
+    }
+
+    /// Counts the problems found in a given set of attributes by severity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use protobuf::Enum;
+    /// use up_rust::{UAttributesValidators, UCode, UMessageBuilder, UUri};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let invoked_method = UUri::try_from("//my-vehicle/4210/5/64AB")?;
+    /// let reply_to_address = UUri::try_from("//my-cloud/BA4C/1/0")?;
+    /// let request_message = UMessageBuilder::request(invoked_method, reply_to_address, 5000).build()?;
+    /// let response_message = UMessageBuilder::response_for_request(&request_message.attributes)
+    ///     .with_comm_status(UCode::OK.value())
+    ///     .build()?;
+    ///
+    /// let validator = UAttributesValidators::Response.validator();
+    /// let summary = validator.summarize(&response_message.attributes);
+    /// assert!(summary.is_valid());
+    /// assert_eq!(summary.warnings, 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn summarize(&self, attributes: &UAttributes) -> ValidationSummary {
+        self.issues(attributes).iter().fold(
+            ValidationSummary::default(),
+            |mut summary, (severity, _message)| {
+                match severity {
+                    Severity::Error => summary.errors += 1,
+                    Severity::Warning => summary.warnings += 1,
+                }
+                summary
+            },
+        )
+    }
+
     /// Verifies that this validator is appropriate for a set of attributes.
     ///
     /// # Errors
@@ -108,6 +241,46 @@ pub trait UAttributesValidator: Send {
         Ok(())
     }
 
+    /// Verifies that a set of attributes contains a time-to-live that does not exceed a maximum value.
+    ///
+    /// This check is not part of [`UAttributesValidator::validate`] but can be used by transports
+    /// that encode the time-to-live using fewer bits than [`UAttributes::ttl`] supports.
+    ///
+    /// # Arguments
+    ///
+    /// * `attributes` - The attributes to check.
+    /// * `max_ttl` - The maximum time-to-live (milliseconds) supported by the transport.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`UAttributes::ttl`] contains a value greater than `max_ttl`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{UAttributes, UAttributesValidators};
+    ///
+    /// let attributes = UAttributes {
+    ///     ttl: Some(70_000),
+    ///     ..Default::default()
+    /// };
+    /// let validator = UAttributesValidators::Publish.validator();
+    /// assert!(validator.validate_ttl_range(&attributes, 0xFFFF).is_err());
+    /// assert!(validator.validate_ttl_range(&attributes, 100_000).is_ok());
+    /// ```
+    fn validate_ttl_range(
+        &self,
+        attributes: &UAttributes,
+        max_ttl: u32,
+    ) -> Result<(), UAttributesError> {
+        match attributes.ttl {
+            Some(ttl) if ttl > max_ttl => Err(UAttributesError::validation_error(format!(
+                "TTL [{ttl}] exceeds maximum value supported by transport [{max_ttl}]"
+            ))),
+            _ => Ok(()),
+        }
+    }
+
     /// Verifies that a set of attributes contains a valid source URI.
     ///
     /// # Errors
@@ -119,6 +292,38 @@ pub trait UAttributesValidator: Send {
     fn validate_sink(&self, attributes: &UAttributes) -> Result<(), UAttributesError>;
 }
 
+impl dyn UAttributesValidator + '_ {
+    /// Gets this validator as [`Any`].
+    ///
+    /// This allows downcasting a boxed validator to its concrete type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{PublishValidator, RequestValidator, UAttributesValidators};
+    ///
+    /// let validator = UAttributesValidators::Request.validator();
+    /// assert!(validator.as_any().downcast_ref::<RequestValidator>().is_some());
+    /// assert!(validator.as_any().downcast_ref::<PublishValidator>().is_none());
+    /// ```
+    pub fn as_any(&self) -> &dyn Any {
+        self.any_ref()
+    }
+}
+
+fn join_errors(errors: Vec<UAttributesError>) -> Result<(), UAttributesError> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        let error_message = errors
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>()
+            .join("; ");
+        Err(UAttributesError::validation_error(error_message))
+    }
+}
+
 /// Verifies that a set of attributes contains a priority that is appropriate for an RPC request message.
 ///
 /// # Errors
@@ -207,6 +412,48 @@ impl UAttributesValidators {
         Self::get_validator(attributes.type_.enum_value_or_default())
     }
 
+    /// Gets a validator that can be used to check a given set of attributes, rejecting attributes
+    /// of unspecified type.
+    ///
+    /// In contrast to [`UAttributesValidators::get_validator_for_attributes`], this function does not
+    /// fall back to the publish message validator, because attributes with an unspecified (or unknown)
+    /// message type have most likely not been initialized properly.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`UAttributes::type_`] is [`UMessageType::UMESSAGE_TYPE_UNSPECIFIED`]
+    /// or contains an unknown message type code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{UAttributes, UAttributesValidators, UMessageType};
+    ///
+    /// let attributes = UAttributes::default();
+    /// assert!(UAttributesValidators::try_get_validator_for_attributes(&attributes).is_err());
+    ///
+    /// let attributes = UAttributes {
+    ///    type_: UMessageType::UMESSAGE_TYPE_REQUEST.into(),
+    ///    ..Default::default()
+    /// };
+    /// let validator = UAttributesValidators::try_get_validator_for_attributes(&attributes).unwrap();
+    /// assert_eq!(validator.message_type(), UMessageType::UMESSAGE_TYPE_REQUEST);
+    /// ```
+    pub fn try_get_validator_for_attributes(
+        attributes: &UAttributes,
+    ) -> Result<Box<dyn UAttributesValidator>, UAttributesError> {
+        match attributes.type_.enum_value() {
+            Ok(UMessageType::UMESSAGE_TYPE_UNSPECIFIED) => Err(UAttributesError::validation_error(
+                "Message type is unspecified",
+            )),
+            Ok(message_type) => Ok(Self::get_validator(message_type)),
+            Err(unknown_code) => Err(UAttributesError::validation_error(format!(
+                "Unknown Message Type code [{}]",
+                unknown_code
+            ))),
+        }
+    }
+
     /// Gets a validator that can be used to check attributes of a given type of message.
     ///
     /// # Examples
@@ -237,6 +484,89 @@ impl UAttributesValidators {
     }
 }
 
+/// A registry of validators to use for checking attributes of particular types of messages.
+///
+/// The registry initially contains the built-in validators for all message types. Custom validators
+/// can be registered in order to enforce additional, domain specific rules.
+pub struct ValidatorRegistry {
+    validators: HashMap<UMessageType, Box<dyn UAttributesValidator>>,
+}
+
+impl Default for ValidatorRegistry {
+    fn default() -> Self {
+        let validators = [
+            UAttributesValidators::Publish,
+            UAttributesValidators::Notification,
+            UAttributesValidators::Request,
+            UAttributesValidators::Response,
+        ]
+        .iter()
+        .map(|v| {
+            let validator = v.validator();
+            (validator.message_type(), validator)
+        })
+        .collect();
+        ValidatorRegistry { validators }
+    }
+}
+
+impl ValidatorRegistry {
+    /// Creates a new registry containing the built-in validators.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a validator for a type of message.
+    ///
+    /// # Arguments
+    ///
+    /// * `message_type` - The type of message to use the validator for.
+    /// * `validator` - The validator.
+    ///
+    /// # Returns
+    ///
+    /// The validator that has previously been registered for the message type, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{PublishValidator, UMessageType, ValidatorRegistry};
+    ///
+    /// let mut registry = ValidatorRegistry::new();
+    /// let previous_validator = registry.register(UMessageType::UMESSAGE_TYPE_PUBLISH, Box::new(PublishValidator));
+    /// assert!(previous_validator.is_some());
+    /// ```
+    pub fn register(
+        &mut self,
+        message_type: UMessageType,
+        validator: Box<dyn UAttributesValidator>,
+    ) -> Option<Box<dyn UAttributesValidator>> {
+        self.validators.insert(message_type, validator)
+    }
+
+    /// Gets the validator to use for checking attributes of a given type of message.
+    ///
+    /// Falls back to the validator registered for publish messages if no validator has been
+    /// registered for the given type, in the same way as [`UAttributesValidators::get_validator`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{UMessageType, ValidatorRegistry};
+    ///
+    /// let registry = ValidatorRegistry::new();
+    /// let validator = registry.get(UMessageType::UMESSAGE_TYPE_REQUEST);
+    /// assert_eq!(validator.message_type(), UMessageType::UMESSAGE_TYPE_REQUEST);
+    /// ```
+    pub fn get(&self, message_type: UMessageType) -> &dyn UAttributesValidator {
+        if let Some(validator) = self.validators.get(&message_type) {
+            validator.as_ref()
+        } else {
+            self.validators[&UMessageType::UMESSAGE_TYPE_PUBLISH].as_ref()
+        }
+    }
+}
+
 /// Validates attributes describing a Publish message.
 pub struct PublishValidator;
 
@@ -257,7 +587,11 @@ impl UAttributesValidator for PublishValidator {
     /// * [`UAttributesValidator::validate_source`]
     /// * [`UAttributesValidator::validate_sink`]
     fn validate(&self, attributes: &UAttributes) -> Result<(), UAttributesError> {
-        let error_message = vec![
+        join_errors(self.validation_errors(attributes))
+    }
+
+    fn validation_errors(&self, attributes: &UAttributes) -> Vec<UAttributesError> {
+        vec![
             self.validate_type(attributes),
             self.validate_id(attributes),
             self.validate_source(attributes),
@@ -265,15 +599,7 @@ impl UAttributesValidator for PublishValidator {
         ]
         .into_iter()
         .filter_map(Result::err)
-        .map(|e| e.to_string())
-        .collect::<Vec<_>>()
-        .join("; ");
-
-        if error_message.is_empty() {
-            Ok(())
-        } else {
-            Err(UAttributesError::validation_error(error_message))
-        }
+        .collect()
     }
 
     /// Verifies that attributes for a publish message contain a valid source URI.
@@ -316,6 +642,23 @@ impl UAttributesValidator for PublishValidator {
 /// Validates attributes describing a Notification message.
 pub struct NotificationValidator;
 
+impl NotificationValidator {
+    /// Verifies that a set of attributes does not contain a communication status.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`UAttributes::commstatus`] contains any value.
+    pub fn validate_commstatus(&self, attributes: &UAttributes) -> Result<(), UAttributesError> {
+        if attributes.commstatus.is_some() {
+            Err(UAttributesError::validation_error(
+                "Attributes for a notification message must not contain a communication status",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
 impl UAttributesValidator for NotificationValidator {
     fn message_type(&self) -> UMessageType {
         UMessageType::UMESSAGE_TYPE_NOTIFICATION
@@ -332,24 +675,22 @@ impl UAttributesValidator for NotificationValidator {
     /// * [`UAttributesValidator::validate_id`]
     /// * [`UAttributesValidator::validate_source`]
     /// * [`UAttributesValidator::validate_sink`]
+    /// * [`NotificationValidator::validate_commstatus`]
     fn validate(&self, attributes: &UAttributes) -> Result<(), UAttributesError> {
-        let error_message = vec![
+        join_errors(self.validation_errors(attributes))
+    }
+
+    fn validation_errors(&self, attributes: &UAttributes) -> Vec<UAttributesError> {
+        vec![
             self.validate_type(attributes),
             self.validate_id(attributes),
             self.validate_source(attributes),
             self.validate_sink(attributes),
+            self.validate_commstatus(attributes),
         ]
         .into_iter()
         .filter_map(Result::err)
-        .map(|e| e.to_string())
-        .collect::<Vec<_>>()
-        .join("; ");
-
-        if error_message.is_empty() {
-            Ok(())
-        } else {
-            Err(UAttributesError::validation_error(error_message))
-        }
+        .collect()
     }
 
     /// Verifies that attributes for a notification message contain a source URI.
@@ -360,6 +701,7 @@ impl UAttributesValidator for NotificationValidator {
     ///
     /// * if the attributes do not contain a source URI, or
     /// * if the source URI is an RPC response URI, or
+    /// * if the source URI's authority name is too long, or
     /// * if the source URI contains any wildcards.
     fn validate_source(&self, attributes: &UAttributes) -> Result<(), UAttributesError> {
         if let Some(source) = attributes.source.as_ref() {
@@ -368,13 +710,16 @@ impl UAttributesValidator for NotificationValidator {
                     "Origin must not be an RPC response URI",
                 ))
             } else {
-                source.verify_no_wildcards().map_err(|e| {
-                    UAttributesError::validation_error(format!("Invalid source URI: {}", e))
-                })
+                source
+                    .verify_authority_name()
+                    .and_then(|_| source.verify_no_wildcards())
+                    .map_err(|e| {
+                        UAttributesError::validation_error(format!("Invalid source URI: {}", e))
+                    })
             }
         } else {
             Err(UAttributesError::validation_error(
-                "Attributes must contain a source URI",
+                "Attributes for a notification message must contain a source URI",
             ))
         }
     }
@@ -387,6 +732,7 @@ impl UAttributesValidator for NotificationValidator {
     ///
     /// * if the attributes do not contain a sink URI, or
     /// * if the sink URI's resource ID is != 0, or
+    /// * if the sink URI's authority name is too long, or
     /// * if the sink URI contains any wildcards.
     fn validate_sink(&self, attributes: &UAttributes) -> Result<(), UAttributesError> {
         if let Some(sink) = attributes.sink.as_ref() {
@@ -395,9 +741,11 @@ impl UAttributesValidator for NotificationValidator {
                     "Destination's resource ID must be 0",
                 ))
             } else {
-                sink.verify_no_wildcards().map_err(|e| {
-                    UAttributesError::validation_error(format!("Invalid sink URI: {}", e))
-                })
+                sink.verify_authority_name()
+                    .and_then(|_| sink.verify_no_wildcards())
+                    .map_err(|e| {
+                        UAttributesError::validation_error(format!("Invalid sink URI: {}", e))
+                    })
             }
         } else {
             Err(UAttributesError::validation_error(
@@ -417,11 +765,51 @@ impl RequestValidator {
     ///
     /// Returns an error if [`UAttributes::ttl`] (time-to-live) is empty or contains a value less than 1.
     pub fn validate_ttl(&self, attributes: &UAttributes) -> Result<(), UAttributesError> {
+        self.validate_ttl_with_minimum(attributes, 1)
+    }
+
+    /// Verifies that a set of attributes representing an RPC request contain a time-to-live
+    /// that is not shorter than a given minimum.
+    ///
+    /// Deployments may use this to prevent requests from timing out immediately, e.g. due
+    /// to clock skew between the involved devices.
+    ///
+    /// # Arguments
+    ///
+    /// * `attributes` - The attributes to check.
+    /// * `min_ttl_ms` - The minimum time-to-live in milliseconds. A value of 1 is equivalent
+    ///   to [`RequestValidator::validate_ttl`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`UAttributes::ttl`] (time-to-live) is empty, contains a value less than 1
+    /// or contains a value less than `min_ttl_ms`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{RequestValidator, UAttributes};
+    ///
+    /// let attributes = UAttributes {
+    ///     ttl: Some(50),
+    ///     ..Default::default()
+    /// };
+    /// assert!(RequestValidator.validate_ttl(&attributes).is_ok());
+    /// assert!(RequestValidator.validate_ttl_with_minimum(&attributes, 100).is_err());
+    /// ```
+    pub fn validate_ttl_with_minimum(
+        &self,
+        attributes: &UAttributes,
+        min_ttl_ms: u32,
+    ) -> Result<(), UAttributesError> {
         match attributes.ttl {
-            Some(ttl) if ttl > 0 => Ok(()),
-            Some(invalid_ttl) => Err(UAttributesError::validation_error(format!(
-                "RPC request message's TTL must be a positive integer [{invalid_ttl}]"
+            Some(0) => Err(UAttributesError::validation_error(
+                "RPC request message's TTL must be a positive integer [0]",
+            )),
+            Some(ttl) if ttl < min_ttl_ms => Err(UAttributesError::validation_error(format!(
+                "RPC request message's TTL [{ttl}] must not be less than [{min_ttl_ms}]"
             ))),
+            Some(_) => Ok(()),
             None => Err(UAttributesError::validation_error(
                 "RPC request message must contain a TTL",
             )),
@@ -448,7 +836,11 @@ impl UAttributesValidator for RequestValidator {
     /// * [`UAttributesValidator::validate_sink`]
     /// * `validate_rpc_priority`
     fn validate(&self, attributes: &UAttributes) -> Result<(), UAttributesError> {
-        let error_message = vec![
+        join_errors(self.validation_errors(attributes))
+    }
+
+    fn validation_errors(&self, attributes: &UAttributes) -> Vec<UAttributesError> {
+        vec![
             self.validate_type(attributes),
             self.validate_id(attributes),
             self.validate_ttl(attributes),
@@ -458,15 +850,7 @@ impl UAttributesValidator for RequestValidator {
         ]
         .into_iter()
         .filter_map(Result::err)
-        .map(|e| e.to_string())
-        .collect::<Vec<_>>()
-        .join("; ");
-
-        if error_message.is_empty() {
-            Ok(())
-        } else {
-            Err(UAttributesError::validation_error(error_message))
-        }
+        .collect()
     }
 
     /// Verifies that attributes for a message representing an RPC request contain a reply-to-address.
@@ -567,7 +951,11 @@ impl UAttributesValidator for ResponseValidator {
     /// * [`ResponseValidator::validate_commstatus`]
     /// * `validate_rpc_priority`
     fn validate(&self, attributes: &UAttributes) -> Result<(), UAttributesError> {
-        let error_message = vec![
+        join_errors(self.validation_errors(attributes))
+    }
+
+    fn validation_errors(&self, attributes: &UAttributes) -> Vec<UAttributesError> {
+        vec![
             self.validate_type(attributes),
             self.validate_id(attributes),
             self.validate_source(attributes),
@@ -578,14 +966,23 @@ impl UAttributesValidator for ResponseValidator {
         ]
         .into_iter()
         .filter_map(Result::err)
-        .map(|e| e.to_string())
-        .collect::<Vec<_>>()
-        .join("; ");
+        .collect()
+    }
 
-        if error_message.is_empty() {
-            Ok(())
+    /// Checks a given set of attributes for configurations that are valid but discouraged.
+    ///
+    /// # Returns
+    ///
+    /// A warning if [`UAttributes::commstatus`] explicitly contains [`UCode::OK`], which is
+    /// the same as not containing a communication status at all.
+    fn warnings(&self, attributes: &UAttributes) -> Vec<String> {
+        if attributes
+            .commstatus
+            .is_some_and(|status| status.enum_value() == Ok(UCode::OK))
+        {
+            vec!["Communication status OK should be omitted".to_string()]
         } else {
-            Err(UAttributesError::validation_error(error_message))
+            Vec::new()
         }
     }
 
@@ -625,6 +1022,9 @@ impl UAttributesValidator for ResponseValidator {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+    use std::thread;
+
     use protobuf::EnumOrUnknown;
     use test_case::test_case;
 
@@ -669,6 +1069,187 @@ mod tests {
         assert_eq!(validator.message_type(), expected_validator_type);
     }
 
+    #[test_case(None, 0; "for missing commstatus")]
+    #[test_case(Some(EnumOrUnknown::from(UCode::OK)), 1; "for OK commstatus")]
+    #[test_case(Some(EnumOrUnknown::from(UCode::CANCELLED)), 0; "for CANCELLED commstatus")]
+    fn test_summarize_reports_warning_for_discouraged_commstatus(
+        commstatus: Option<EnumOrUnknown<UCode>>,
+        expected_warnings: usize,
+    ) {
+        let attributes = UAttributes {
+            type_: UMessageType::UMESSAGE_TYPE_RESPONSE.into(),
+            id: Some(UUIDBuilder::build()).into(),
+            priority: UPriority::UPRIORITY_CS4.into(),
+            reqid: Some(UUIDBuilder::build()).into(),
+            source: Some(method_to_invoke()).into(),
+            sink: Some(reply_to_address()).into(),
+            commstatus,
+            ..Default::default()
+        };
+        let validator = UAttributesValidators::Response.validator();
+        assert!(validator.validate(&attributes).is_ok());
+        let summary = validator.summarize(&attributes);
+        assert!(summary.is_valid());
+        assert_eq!(summary.warnings, expected_warnings);
+    }
+
+    #[test]
+    fn test_summarize_counts_each_error() {
+        let attributes = UAttributes {
+            type_: UMessageType::UMESSAGE_TYPE_NOTIFICATION.into(),
+            id: Some(UUIDBuilder::build()).into(),
+            ..Default::default()
+        };
+        let validator = UAttributesValidators::Notification.validator();
+        assert_eq!(
+            validator.summarize(&attributes),
+            ValidationSummary {
+                errors: 2,
+                warnings: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_validation_errors_are_reported_in_stable_order() {
+        let attributes = UAttributes {
+            type_: UMessageType::UMESSAGE_TYPE_REQUEST.into(),
+            id: Some(UUIDBuilder::build()).into(),
+            priority: UPriority::UPRIORITY_CS2.into(),
+            source: Some(reply_to_address()).into(),
+            ttl: Some(0),
+            ..Default::default()
+        };
+        let validator = UAttributesValidators::Request.validator();
+
+        let errors = validator
+            .validation_errors(&attributes)
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(errors.len(), 3);
+        assert!(errors[0].contains("TTL must be a positive integer"));
+        assert!(errors[1].contains("must contain a method-to-invoke"));
+        assert!(errors[2].contains("priority of at least CS4"));
+
+        let error_message = validator.validate(&attributes).unwrap_err().to_string();
+        assert!(error_message.ends_with(&errors.join("; ")));
+    }
+
+    #[test]
+    fn test_validator_can_be_shared_among_threads() {
+        let validator: Arc<dyn UAttributesValidator> =
+            Arc::from(UAttributesValidators::Publish.validator());
+        let handles = (0..4)
+            .map(|_| {
+                let validator = Arc::clone(&validator);
+                thread::spawn(move || {
+                    let attributes = UAttributes {
+                        type_: UMessageType::UMESSAGE_TYPE_PUBLISH.into(),
+                        id: Some(UUIDBuilder::build()).into(),
+                        priority: UPriority::UPRIORITY_CS1.into(),
+                        source: Some(publish_topic()).into(),
+                        ..Default::default()
+                    };
+                    validator.validate(&attributes).is_ok()
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            assert!(handle.join().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_as_any_supports_downcasting_to_concrete_validator() {
+        let validator = UAttributesValidators::Request.validator();
+        assert!(validator
+            .as_any()
+            .downcast_ref::<RequestValidator>()
+            .is_some());
+        assert!(validator
+            .as_any()
+            .downcast_ref::<ResponseValidator>()
+            .is_none());
+    }
+
+    struct RestrictedPublishValidator;
+
+    impl UAttributesValidator for RestrictedPublishValidator {
+        fn message_type(&self) -> UMessageType {
+            UMessageType::UMESSAGE_TYPE_PUBLISH
+        }
+
+        fn validate(&self, attributes: &UAttributes) -> Result<(), UAttributesError> {
+            PublishValidator.validate(attributes)?;
+            self.validate_source(attributes)
+        }
+
+        fn validate_source(&self, attributes: &UAttributes) -> Result<(), UAttributesError> {
+            PublishValidator.validate_source(attributes)?;
+            if attributes.source.as_ref() == Some(&publish_topic()) {
+                Err(UAttributesError::validation_error("Topic must not be used"))
+            } else {
+                Ok(())
+            }
+        }
+
+        fn validate_sink(&self, attributes: &UAttributes) -> Result<(), UAttributesError> {
+            PublishValidator.validate_sink(attributes)
+        }
+    }
+
+    #[test]
+    fn test_validator_registry_uses_custom_validator() {
+        let attributes = UAttributes {
+            type_: UMessageType::UMESSAGE_TYPE_PUBLISH.into(),
+            id: Some(UUIDBuilder::build()).into(),
+            source: Some(publish_topic()).into(),
+            ..Default::default()
+        };
+        let mut registry = ValidatorRegistry::new();
+        assert!(registry
+            .get(UMessageType::UMESSAGE_TYPE_PUBLISH)
+            .validate(&attributes)
+            .is_ok());
+
+        registry.register(
+            UMessageType::UMESSAGE_TYPE_PUBLISH,
+            Box::new(RestrictedPublishValidator),
+        );
+        assert!(registry
+            .get(UMessageType::UMESSAGE_TYPE_PUBLISH)
+            .validate(&attributes)
+            .is_err());
+        // the custom validator is also used as the fallback for unspecified message types
+        assert!(registry
+            .get(UMessageType::UMESSAGE_TYPE_UNSPECIFIED)
+            .as_any()
+            .downcast_ref::<RestrictedPublishValidator>()
+            .is_some());
+        // validators for other message types are not affected
+        assert!(registry
+            .get(UMessageType::UMESSAGE_TYPE_NOTIFICATION)
+            .as_any()
+            .downcast_ref::<NotificationValidator>()
+            .is_some());
+    }
+
+    #[test]
+    fn test_try_get_validator_for_attributes_fails_for_unspecified_type() {
+        let result =
+            UAttributesValidators::try_get_validator_for_attributes(&UAttributes::default());
+        assert!(result
+            .err()
+            .is_some_and(|e| e.to_string().contains("Message type is unspecified")));
+
+        let attributes = UAttributes {
+            type_: EnumOrUnknown::from_i32(20),
+            ..Default::default()
+        };
+        assert!(UAttributesValidators::try_get_validator_for_attributes(&attributes).is_err());
+    }
+
     #[test_case(UMessageType::UMESSAGE_TYPE_PUBLISH, None, None, false; "for Publish message without ID nor TTL")]
     #[test_case(UMessageType::UMESSAGE_TYPE_PUBLISH, None, Some(0), false; "for Publish message without ID with TTL 0")]
     #[test_case(UMessageType::UMESSAGE_TYPE_PUBLISH, None, Some(500), false; "for Publish message without ID with TTL")]
@@ -715,11 +1296,25 @@ mod tests {
         assert!(validator.is_expired(&attributes).is_err() == should_be_expired);
     }
 
+    #[test_case(None, true; "succeeds for missing TTL")]
+    #[test_case(Some(0), true; "succeeds for TTL 0")]
+    #[test_case(Some(0xFFFF), true; "succeeds for TTL at limit")]
+    #[test_case(Some(0x1_0000), false; "fails for TTL exceeding limit")]
+    fn test_validate_ttl_range(ttl: Option<u32>, expected_result: bool) {
+        let attributes = UAttributes {
+            ttl,
+            ..Default::default()
+        };
+        let validator = UAttributesValidators::Request.validator();
+        assert!(validator.validate_ttl_range(&attributes, 0xFFFF).is_ok() == expected_result);
+    }
+
     #[test_case(Some(UUIDBuilder::build()), Some(publish_topic()), None, None, true; "succeeds for topic only")]
     #[test_case(Some(UUIDBuilder::build()), Some(publish_topic()), Some(destination()), None, false; "fails for message containing destination")]
     #[test_case(Some(UUIDBuilder::build()), Some(publish_topic()), None, Some(100), true; "succeeds for valid attributes")]
     #[test_case(Some(UUIDBuilder::build()), None, None, None, false; "fails for missing topic")]
     #[test_case(Some(UUIDBuilder::build()), Some(UUri { resource_id: 0x54, ..Default::default()}), None, None, false; "fails for invalid topic")]
+    #[test_case(Some(UUIDBuilder::build()), Some(UUri { authority_name: "a".repeat(UUri::MAX_AUTHORITY_NAME_LEN + 1), ..publish_topic() }), None, None, false; "fails for topic with too long authority name")]
     #[test_case(None, Some(publish_topic()), None, None, false; "fails for missing message ID")]
     #[test_case(
         Some(UUID {
@@ -774,6 +1369,7 @@ mod tests {
     #[test_case(Some(UUIDBuilder::build()), None, Some(destination()), None, false; "fails for missing origin")]
     #[test_case(Some(UUIDBuilder::build()), Some(UUri::default()), Some(destination()), None, false; "fails for invalid origin")]
     #[test_case(Some(UUIDBuilder::build()), Some(origin()), Some(UUri { ue_id: 0xabcd, ue_version_major: 0x01, resource_id: 0x0011, ..Default::default() }), None, false; "fails for invalid destination")]
+    #[test_case(Some(UUIDBuilder::build()), Some(origin()), Some(UUri { authority_name: "a".repeat(UUri::MAX_AUTHORITY_NAME_LEN + 1), ..destination() }), None, false; "fails for destination with too long authority name")]
     #[test_case(Some(UUIDBuilder::build()), None, None, None, false; "fails for neither origin nor destination")]
     #[test_case(None, Some(origin()), Some(destination()), None, false; "fails for missing message ID")]
     #[test_case(
@@ -823,6 +1419,48 @@ mod tests {
         }
     }
 
+    #[test_case(None, true; "succeeds for missing commstatus")]
+    #[test_case(Some(EnumOrUnknown::from(UCode::OK)), false; "fails for OK commstatus")]
+    #[test_case(Some(EnumOrUnknown::from(UCode::CANCELLED)), false; "fails for CANCELLED commstatus")]
+    fn test_validate_attributes_for_notification_message_with_commstatus(
+        commstatus: Option<EnumOrUnknown<UCode>>,
+        expected_result: bool,
+    ) {
+        let attributes = UAttributes {
+            type_: UMessageType::UMESSAGE_TYPE_NOTIFICATION.into(),
+            id: Some(UUIDBuilder::build()).into(),
+            priority: UPriority::UPRIORITY_CS1.into(),
+            source: Some(origin()).into(),
+            sink: Some(destination()).into(),
+            commstatus,
+            ..Default::default()
+        };
+        let status = UAttributesValidators::Notification
+            .validator()
+            .validate(&attributes);
+        assert!(status.is_ok() == expected_result);
+    }
+
+    #[test]
+    fn test_validate_notification_reports_missing_origin_and_destination() {
+        let attributes = UAttributes {
+            type_: UMessageType::UMESSAGE_TYPE_NOTIFICATION.into(),
+            id: Some(UUIDBuilder::build()).into(),
+            priority: UPriority::UPRIORITY_CS1.into(),
+            ..Default::default()
+        };
+        let error_message = UAttributesValidators::Notification
+            .validator()
+            .validate(&attributes)
+            .unwrap_err()
+            .to_string();
+        assert!(error_message
+            .contains("Attributes for a notification message must contain a source URI"));
+        assert!(
+            error_message.contains("Attributes for a notification message must contain a sink URI")
+        );
+    }
+
     #[test_case(Some(UUIDBuilder::build()), Some(method_to_invoke()), Some(reply_to_address()), None, Some(2000), Some(UPriority::UPRIORITY_CS4), None, true; "succeeds for mandatory attributes")]
     #[test_case(Some(UUIDBuilder::build()), Some(method_to_invoke()), Some(reply_to_address()), Some(1), Some(2000), Some(UPriority::UPRIORITY_CS4), Some(String::from("token")), true; "succeeds for valid attributes")]
     #[test_case(None, Some(method_to_invoke()), Some(reply_to_address()), Some(1), Some(2000), Some(UPriority::UPRIORITY_CS4), Some(String::from("token")), false; "fails for missing message ID")]
@@ -845,6 +1483,7 @@ mod tests {
     #[test_case(Some(UUIDBuilder::build()), Some(method_to_invoke()), Some(UUri { resource_id: 0x0001, ..Default::default()}), None, Some(2000), Some(UPriority::UPRIORITY_CS4), None, false; "fails for invalid reply-to-address")]
     #[test_case(Some(UUIDBuilder::build()), None, Some(reply_to_address()), None, Some(2000), Some(UPriority::UPRIORITY_CS4), None, false; "fails for missing method-to-invoke")]
     #[test_case(Some(UUIDBuilder::build()), Some(UUri::default()), Some(reply_to_address()), None, Some(2000), Some(UPriority::UPRIORITY_CS4), None, false; "fails for invalid method-to-invoke")]
+    #[test_case(Some(UUIDBuilder::build()), Some(reply_to_address()), Some(reply_to_address()), None, Some(2000), Some(UPriority::UPRIORITY_CS4), None, false; "fails for RPC response URI as method-to-invoke")]
     #[test_case(Some(UUIDBuilder::build()), Some(method_to_invoke()), Some(reply_to_address()), Some(1), Some(2000), None, None, false; "fails for missing priority")]
     #[test_case(Some(UUIDBuilder::build()), Some(method_to_invoke()), Some(reply_to_address()), Some(1), Some(2000), Some(UPriority::UPRIORITY_CS3), None, false; "fails for invalid priority")]
     #[test_case(Some(UUIDBuilder::build()), Some(method_to_invoke()), Some(reply_to_address()), None, None, Some(UPriority::UPRIORITY_CS4), None, false; "fails for missing ttl")]
@@ -892,6 +1531,25 @@ mod tests {
         }
     }
 
+    #[test_case(None, 100, false; "fails for missing ttl")]
+    #[test_case(Some(0), 1, false; "fails for zero ttl")]
+    #[test_case(Some(1), 1, true; "succeeds for default minimum")]
+    #[test_case(Some(50), 100, false; "fails for ttl less than minimum")]
+    #[test_case(Some(100), 100, true; "succeeds for ttl equal to minimum")]
+    #[test_case(Some(200), 100, true; "succeeds for ttl greater than minimum")]
+    fn test_validate_ttl_with_minimum(ttl: Option<u32>, min_ttl_ms: u32, expected_result: bool) {
+        let attributes = UAttributes {
+            ttl,
+            ..Default::default()
+        };
+        assert!(
+            RequestValidator
+                .validate_ttl_with_minimum(&attributes, min_ttl_ms)
+                .is_ok()
+                == expected_result
+        );
+    }
+
     #[test_case(Some(UUIDBuilder::build()), Some(reply_to_address()), Some(method_to_invoke()), Some(UUIDBuilder::build()), None, None, Some(UPriority::UPRIORITY_CS4), true; "succeeds for mandatory attributes")]
     #[test_case(Some(UUIDBuilder::build()), Some(reply_to_address()), Some(method_to_invoke()), Some(UUIDBuilder::build()), Some(EnumOrUnknown::from(UCode::CANCELLED)), Some(100), Some(UPriority::UPRIORITY_CS4), true; "succeeds for valid attributes")]
     #[test_case(None, Some(reply_to_address()), Some(method_to_invoke()), Some(UUIDBuilder::build()), Some(EnumOrUnknown::from(UCode::CANCELLED)), Some(100), Some(UPriority::UPRIORITY_CS4), false; "fails for missing message ID")]
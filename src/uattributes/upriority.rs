@@ -11,9 +11,9 @@
  * SPDX-License-Identifier: Apache-2.0
  ********************************************************************************/
 
-use protobuf::EnumFull;
+use protobuf::{Enum, EnumFull};
 
-use crate::uattributes::UAttributesError;
+use crate::uattributes::{UAttributes, UAttributesError, UMessageType};
 pub use crate::up_core_api::uattributes::UPriority;
 use crate::up_core_api::uoptions::exts::ce_name;
 
@@ -38,6 +38,47 @@ impl UPriority {
             .unwrap_or_default()
     }
 
+    /// Determines the priority to use for a message.
+    ///
+    /// The default priorities are defined in the
+    /// [uProtocol specification](https://github.com/eclipse-uprotocol/up-spec/blob/main/basics/qos.adoc).
+    ///
+    /// # Arguments
+    ///
+    /// * `message_type` - The type of message.
+    /// * `declared` - The priority that has been set on the message, if any.
+    ///
+    /// # Returns
+    ///
+    /// The declared priority, if it is not [`UPriority::UPRIORITY_UNSPECIFIED`]. Otherwise,
+    /// [`UPriority::UPRIORITY_CS4`] for RPC messages and [`UPriority::UPRIORITY_CS1`] for all other messages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use up_rust::{UMessageType, UPriority};
+    ///
+    /// assert_eq!(
+    ///     UPriority::effective_for(UMessageType::UMESSAGE_TYPE_REQUEST, None),
+    ///     UPriority::UPRIORITY_CS4
+    /// );
+    /// assert_eq!(
+    ///     UPriority::effective_for(UMessageType::UMESSAGE_TYPE_PUBLISH, Some(UPriority::UPRIORITY_CS3)),
+    ///     UPriority::UPRIORITY_CS3
+    /// );
+    /// ```
+    pub fn effective_for(message_type: UMessageType, declared: Option<UPriority>) -> UPriority {
+        match declared {
+            Some(priority) if priority != UPriority::UPRIORITY_UNSPECIFIED => priority,
+            _ => match message_type {
+                UMessageType::UMESSAGE_TYPE_REQUEST | UMessageType::UMESSAGE_TYPE_RESPONSE => {
+                    UPriority::UPRIORITY_CS4
+                }
+                _ => UPriority::UPRIORITY_CS1,
+            },
+        }
+    }
+
     /// Gets the priority for a string.
     ///
     /// The encoding of priorities to strings is defined in the
@@ -80,3 +121,75 @@ impl UPriority {
             .ok_or_else(|| UAttributesError::parsing_error(format!("unknown priority [{}]", prio)))
     }
 }
+
+/// Checks if a message should be dropped when shedding load based on priority.
+///
+/// Messages without a (known) priority are considered to have the default priority
+/// [`UPriority::UPRIORITY_CS1`] as defined by the
+/// [uProtocol specification](https://github.com/eclipse-uprotocol/up-spec/blob/main/basics/qos.adoc).
+///
+/// # Arguments
+///
+/// * `attributes` - The attributes of the message.
+/// * `min_priority` - The minimum priority of messages to keep.
+///
+/// # Returns
+///
+/// `true` if the message's priority is lower than the given minimum priority.
+///
+/// # Examples
+///
+/// ```rust
+/// use up_rust::{should_shed, UAttributes, UPriority};
+///
+/// let attributes = UAttributes {
+///     priority: UPriority::UPRIORITY_CS0.into(),
+///     ..Default::default()
+/// };
+/// assert!(should_shed(&attributes, UPriority::UPRIORITY_CS2));
+/// assert!(!should_shed(&attributes, UPriority::UPRIORITY_CS0));
+/// ```
+pub fn should_shed(attributes: &UAttributes, min_priority: UPriority) -> bool {
+    let priority = match attributes.priority.enum_value_or_default() {
+        UPriority::UPRIORITY_UNSPECIFIED => UPriority::UPRIORITY_CS1,
+        priority => priority,
+    };
+    priority.value() < min_priority.value()
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(UPriority::UPRIORITY_CS0, UPriority::UPRIORITY_CS2, true; "sheds CS0 message at CS2 threshold")]
+    #[test_case(UPriority::UPRIORITY_CS2, UPriority::UPRIORITY_CS2, false; "keeps CS2 message at CS2 threshold")]
+    #[test_case(UPriority::UPRIORITY_CS4, UPriority::UPRIORITY_CS2, false; "keeps CS4 message at CS2 threshold")]
+    #[test_case(UPriority::UPRIORITY_UNSPECIFIED, UPriority::UPRIORITY_CS1, false; "keeps unspecified message at CS1 threshold")]
+    #[test_case(UPriority::UPRIORITY_UNSPECIFIED, UPriority::UPRIORITY_CS2, true; "sheds unspecified message at CS2 threshold")]
+    fn test_should_shed(priority: UPriority, min_priority: UPriority, expected_result: bool) {
+        let attributes = UAttributes {
+            priority: priority.into(),
+            ..Default::default()
+        };
+        assert_eq!(should_shed(&attributes, min_priority), expected_result);
+    }
+
+    #[test_case(UMessageType::UMESSAGE_TYPE_REQUEST, None, UPriority::UPRIORITY_CS4; "for request without priority")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_RESPONSE, Some(UPriority::UPRIORITY_UNSPECIFIED), UPriority::UPRIORITY_CS4; "for response with unspecified priority")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_PUBLISH, None, UPriority::UPRIORITY_CS1; "for publish without priority")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_NOTIFICATION, Some(UPriority::UPRIORITY_UNSPECIFIED), UPriority::UPRIORITY_CS1; "for notification with unspecified priority")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_REQUEST, Some(UPriority::UPRIORITY_CS6), UPriority::UPRIORITY_CS6; "for request with declared priority")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_PUBLISH, Some(UPriority::UPRIORITY_CS0), UPriority::UPRIORITY_CS0; "for publish with declared priority")]
+    fn test_effective_for(
+        message_type: UMessageType,
+        declared: Option<UPriority>,
+        expected_priority: UPriority,
+    ) {
+        assert_eq!(
+            UPriority::effective_for(message_type, declared),
+            expected_priority
+        );
+    }
+}
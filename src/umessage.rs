@@ -11,16 +11,30 @@
  * SPDX-License-Identifier: Apache-2.0
  ********************************************************************************/
 
+mod schemaregistry;
 mod umessagebuilder;
 mod umessagetype;
 
+pub use schemaregistry::SchemaRegistry;
 pub use umessagebuilder::*;
 
 pub use crate::up_core_api::umessage::UMessage;
 
-use crate::{UAttributesError, UPayloadFormat};
+use crate::{
+    UAttributesError, UAttributesValidator, UAttributesValidators, UMessageType, UPayloadFormat,
+    UUri,
+};
 use protobuf::{well_known_types::any::Any, Message};
 
+/// The attribute of a message that contains a URI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UriAttribute {
+    /// The message's `source` attribute.
+    Source,
+    /// The message's `sink` attribute.
+    Sink,
+}
+
 #[derive(Debug)]
 pub enum UMessageError {
     AttributesValidationError(UAttributesError),
@@ -105,4 +119,226 @@ impl UMessage {
             Err(UMessageError::from("Payload is empty"))
         }
     }
+
+    /// Creates a single line summary of this message's attributes for logging purposes.
+    ///
+    /// The summary contains the message type, a shortened message ID, the source and sink URIs,
+    /// the time-to-live and the priority. The shortened message ID consists of the second and
+    /// third group of the hyphenated message ID, i.e. the lower 16 bits of the creation time
+    /// and the counter, which differ between messages created by the same uEntity. The message's token
+    /// is never included verbatim but only indicated as `<redacted>`. Absent attributes
+    /// are represented by `-`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{UMessageBuilder, UUri};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let method_to_invoke = UUri::try_from("//my-vehicle/4D123/2/6FA3")?;
+    /// let reply_to_address = UUri::try_from("//my-cloud/9CB3/1/0")?;
+    /// let message = UMessageBuilder::request(method_to_invoke, reply_to_address, 1000)
+    ///     .with_token("my-secret".to_string())
+    ///     .build()?;
+    /// let summary = message.summary();
+    /// assert!(summary.starts_with("REQUEST id="));
+    /// assert!(summary.ends_with("from=//my-cloud/9CB3/1/0 to=//my-vehicle/4D123/2/6FA3 ttl=1000 prio=CS4 token=<redacted>"));
+    /// assert!(!summary.contains("my-secret"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn summary(&self) -> String {
+        let attributes = self.attributes.get_or_default();
+        let message_type = match attributes.type_.enum_value_or_default() {
+            UMessageType::UMESSAGE_TYPE_PUBLISH => "PUBLISH",
+            UMessageType::UMESSAGE_TYPE_NOTIFICATION => "NOTIFICATION",
+            UMessageType::UMESSAGE_TYPE_REQUEST => "REQUEST",
+            UMessageType::UMESSAGE_TYPE_RESPONSE => "RESPONSE",
+            UMessageType::UMESSAGE_TYPE_UNSPECIFIED => "UNSPECIFIED",
+        };
+        let id = attributes.id.as_ref().map_or("-".to_string(), |id| {
+            let id_string = id.to_hyphenated_string();
+            id_string[9..18].to_string()
+        });
+        let source = attributes
+            .source
+            .as_ref()
+            .map_or("-".to_string(), |uri| uri.to_uri(false));
+        let sink = attributes
+            .sink
+            .as_ref()
+            .map_or("-".to_string(), |uri| uri.to_uri(false));
+        let ttl = attributes
+            .ttl
+            .map_or("-".to_string(), |ttl| ttl.to_string());
+        let priority = attributes
+            .priority
+            .enum_value()
+            .map_or("-".to_string(), |priority| priority.to_priority_code());
+
+        let mut summary =
+            format!("{message_type} id={id} from={source} to={sink} ttl={ttl} prio={priority}");
+        if attributes.token.is_some() {
+            summary.push_str(" token=<redacted>");
+        }
+        summary
+    }
+
+    /// Gets all URIs that this message's attributes refer to.
+    ///
+    /// # Returns
+    ///
+    /// The URIs along with the attribute that contains them.
+    /// Absent attributes are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{UMessageBuilder, UUri, UriAttribute};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let topic = UUri::try_from("//my-vehicle/4210/5/F20B")?;
+    /// let message = UMessageBuilder::publish(topic.clone()).build()?;
+    /// assert_eq!(message.referenced_uris(), vec![(UriAttribute::Source, &topic)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn referenced_uris(&self) -> Vec<(UriAttribute, &UUri)> {
+        let mut uris = Vec::with_capacity(2);
+        if let Some(attributes) = self.attributes.as_ref() {
+            if let Some(source) = attributes.source.as_ref() {
+                uris.push((UriAttribute::Source, source));
+            }
+            if let Some(sink) = attributes.sink.as_ref() {
+                uris.push((UriAttribute::Sink, sink));
+            }
+        }
+        uris
+    }
+
+    /// Verifies that all URIs that this message's attributes refer to are valid for the role
+    /// they play in this type of message.
+    ///
+    /// Each of the [referenced URIs](`UMessage::referenced_uris`) is checked using the
+    /// [`UAttributesValidator`] for this message's type. Absent URIs are not reported.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message type is not supported or if any of the URIs is invalid.
+    /// The error's message contains the problems found for each of the URIs, separated by `; `.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{UMessageBuilder, UUri};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let method_to_invoke = UUri::try_from("//my-vehicle/4D123/2/6FA3")?;
+    /// let reply_to_address = UUri::try_from("//my-cloud/9CB3/1/0")?;
+    /// let message = UMessageBuilder::request(method_to_invoke, reply_to_address, 1000).build()?;
+    /// assert!(message.validate_all_uris().is_ok());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate_all_uris(&self) -> Result<(), UAttributesError> {
+        let attributes = self.attributes.get_or_default();
+        let validator = UAttributesValidators::try_get_validator_for_attributes(attributes)?;
+        let errors = self
+            .referenced_uris()
+            .into_iter()
+            .filter_map(|(attribute, _uri)| {
+                let result = match attribute {
+                    UriAttribute::Source => validator.validate_source(attributes),
+                    UriAttribute::Sink => validator.validate_sink(attributes),
+                };
+                result.err()
+            })
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(UAttributesError::validation_error(errors.join("; ")))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UMessageBuilder, UUri};
+
+    #[test]
+    fn test_summary_redacts_token() {
+        let method_to_invoke = UUri::try_from("//my-vehicle/4D123/2/6FA3").unwrap();
+        let reply_to_address = UUri::try_from("//my-cloud/9CB3/1/0").unwrap();
+        let message = UMessageBuilder::request(method_to_invoke, reply_to_address, 1000)
+            .with_token("my-secret-token".to_string())
+            .build()
+            .unwrap();
+        let id = message.attributes.id.to_hyphenated_string();
+
+        let summary = message.summary();
+        assert!(summary.starts_with("REQUEST "));
+        assert!(summary.contains(&format!("id={} ", &id[9..18])));
+        assert!(!summary.contains(&id));
+        assert!(summary.contains("ttl=1000"));
+        assert!(summary.contains("prio=CS4"));
+        assert!(summary.contains("token=<redacted>"));
+        assert!(!summary.contains("my-secret-token"));
+    }
+
+    #[test]
+    fn test_summary_ids_differ_for_consecutive_messages() {
+        let topic = UUri::try_from("//my-vehicle/4D123/2/8000").unwrap();
+        let builder = UMessageBuilder::publish(topic);
+        let summary_id = |message: UMessage| {
+            message
+                .summary()
+                .split(' ')
+                .find_map(|field| field.strip_prefix("id=").map(str::to_string))
+                .unwrap()
+        };
+
+        let first_id = summary_id(builder.build().unwrap());
+        let second_id = summary_id(builder.build().unwrap());
+        assert_ne!(first_id, second_id);
+    }
+
+    #[test]
+    fn test_validate_all_uris_reports_invalid_sink() {
+        let method_to_invoke = UUri::try_from("//my-vehicle/4D123/2/6FA3").unwrap();
+        let reply_to_address = UUri::try_from("//my-cloud/9CB3/1/0").unwrap();
+        let mut message = UMessageBuilder::request(method_to_invoke, reply_to_address, 1000)
+            .build()
+            .unwrap();
+        // an RPC response URI is not a valid method to invoke
+        let invalid_sink = UUri::try_from("//my-vehicle/4D123/2/0").unwrap();
+        message.attributes.as_mut().unwrap().sink = Some(invalid_sink.clone()).into();
+        assert_eq!(
+            message.referenced_uris(),
+            vec![
+                (
+                    UriAttribute::Source,
+                    message.attributes.source.as_ref().unwrap()
+                ),
+                (UriAttribute::Sink, &invalid_sink)
+            ]
+        );
+
+        let error = message.validate_all_uris().unwrap_err();
+        assert!(error.to_string().contains("Invalid sink URI"));
+        assert!(!error.to_string().contains("Invalid source URI"));
+    }
+
+    #[test]
+    fn test_summary_omits_absent_token() {
+        let topic = UUri::try_from("//my-vehicle/4D123/2/8000").unwrap();
+        let message = UMessageBuilder::publish(topic).build().unwrap();
+
+        let summary = message.summary();
+        assert!(summary.starts_with("PUBLISH "));
+        assert!(summary.contains("to=- ttl=-"));
+        assert!(!summary.contains("token="));
+    }
 }
@@ -0,0 +1,147 @@
+/********************************************************************************
+ * Copyright (c) 2024 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+use std::collections::HashSet;
+
+use protobuf::{well_known_types::any::Any, Message};
+
+use crate::{UMessage, UMessageError, UPayloadFormat};
+
+/// A registry of the payload types that are known in a system.
+///
+/// Payload types are identified by the type URL of a [`protobuf::well_known_types::any::Any`],
+/// e.g. `type.googleapis.com/google.protobuf.StringValue`.
+pub trait SchemaRegistry {
+    /// Checks if a payload type is known.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_url` - The type URL identifying the payload type.
+    fn is_known(&self, type_url: &str) -> bool;
+}
+
+impl SchemaRegistry for HashSet<String> {
+    fn is_known(&self, type_url: &str) -> bool {
+        self.contains(type_url)
+    }
+}
+
+impl UMessage {
+    /// Verifies that the type of this message's payload is known to a schema registry.
+    ///
+    /// Only payloads with format [`UPayloadFormat::UPAYLOAD_FORMAT_PROTOBUF_WRAPPED_IN_ANY`]
+    /// declare their type. Messages using any other payload format and messages without payload
+    /// are therefore not checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `registry` - The registry containing the known payload types.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload cannot be deserialized into an `Any` or if the
+    /// type URL of the payload is not known to the registry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashSet;
+    /// use protobuf::well_known_types::wrappers::StringValue;
+    /// use up_rust::{UMessageBuilder, UUri};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let topic = UUri::try_from("//my-vehicle/4210/5/F20B")?;
+    /// let mut payload = StringValue::new();
+    /// payload.value = "Hello".to_string();
+    /// let message = UMessageBuilder::publish(topic).build_with_wrapped_protobuf_payload(&payload)?;
+    ///
+    /// let mut registry = HashSet::new();
+    /// assert!(message.validate_payload_type(&registry).is_err());
+    /// registry.insert("type.googleapis.com/google.protobuf.StringValue".to_string());
+    /// assert!(message.validate_payload_type(&registry).is_ok());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate_payload_type<R: SchemaRegistry + ?Sized>(
+        &self,
+        registry: &R,
+    ) -> Result<(), UMessageError> {
+        match self.payload.as_ref() {
+            Some(payload)
+                if self.attributes.payload_format.enum_value_or_default()
+                    == UPayloadFormat::UPAYLOAD_FORMAT_PROTOBUF_WRAPPED_IN_ANY =>
+            {
+                let any = Any::parse_from_bytes(payload.as_ref())?;
+                if registry.is_known(&any.type_url) {
+                    Ok(())
+                } else {
+                    Err(UMessageError::PayloadError(format!(
+                        "Unknown payload type [{}]",
+                        any.type_url
+                    )))
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use protobuf::well_known_types::wrappers::{Int32Value, StringValue};
+
+    use super::*;
+    use crate::{UMessageBuilder, UUri};
+
+    struct SingleTypeRegistry(&'static str);
+
+    impl SchemaRegistry for SingleTypeRegistry {
+        fn is_known(&self, type_url: &str) -> bool {
+            self.0 == type_url
+        }
+    }
+
+    fn topic() -> UUri {
+        UUri::try_from("//my-vehicle/4210/5/F20B").unwrap()
+    }
+
+    #[test]
+    fn test_validate_payload_type_accepts_known_type() {
+        let registry = SingleTypeRegistry("type.googleapis.com/google.protobuf.StringValue");
+        let message = UMessageBuilder::publish(topic())
+            .build_with_wrapped_protobuf_payload(&StringValue::new())
+            .unwrap();
+        assert!(message.validate_payload_type(&registry).is_ok());
+    }
+
+    #[test]
+    fn test_validate_payload_type_rejects_unknown_type() {
+        let registry = SingleTypeRegistry("type.googleapis.com/google.protobuf.StringValue");
+        let message = UMessageBuilder::publish(topic())
+            .build_with_wrapped_protobuf_payload(&Int32Value::new())
+            .unwrap();
+        assert!(matches!(
+            message.validate_payload_type(&registry),
+            Err(UMessageError::PayloadError(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_payload_type_ignores_undeclared_type() {
+        let registry = SingleTypeRegistry("type.googleapis.com/google.protobuf.StringValue");
+        let message = UMessageBuilder::publish(topic())
+            .build_with_protobuf_payload(&Int32Value::new())
+            .unwrap();
+        assert!(message.validate_payload_type(&registry).is_ok());
+    }
+}
@@ -11,18 +11,83 @@
  * SPDX-License-Identifier: Apache-2.0
  ********************************************************************************/
 
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use bytes::Bytes;
 use protobuf::{well_known_types::any::Any, Enum, EnumOrUnknown, Message, MessageFull};
 
 use crate::uattributes::NotificationValidator;
 use crate::{
-    PublishValidator, RequestValidator, ResponseValidator, UAttributes, UAttributesValidator,
-    UCode, UMessage, UMessageError, UMessageType, UPayloadFormat, UPriority, UUIDBuilder, UUri,
-    UUID,
+    PublishValidator, RequestValidator, ResponseValidator, UAttributes, UAttributesError,
+    UAttributesValidator, UCode, UMessage, UMessageError, UMessageType, UPayloadFormat, UPriority,
+    UUIDBuilder, UUri, UUID,
 };
 
 const PRIORITY_DEFAULT: UPriority = UPriority::UPRIORITY_CS1;
 
+/// A URI to be used as the source of a message.
+///
+/// The builder functions of [`UMessageBuilder`] accept a `Source` (or a plain [`UUri`]) wherever
+/// a message's source is expected. Wrapping a URI explicitly lets the compiler detect a `Source`
+/// being passed in place of a [`Sink`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Source(pub UUri);
+
+impl From<UUri> for Source {
+    fn from(uri: UUri) -> Self {
+        Source(uri)
+    }
+}
+
+impl From<Source> for UUri {
+    fn from(source: Source) -> Self {
+        source.0
+    }
+}
+
+/// A URI to be used as the sink (destination) of a message.
+///
+/// The builder functions of [`UMessageBuilder`] accept a `Sink` (or a plain [`UUri`]) wherever
+/// a message's sink is expected. Wrapping a URI explicitly lets the compiler detect a `Sink`
+/// being passed in place of a [`Source`].
+///
+/// # Examples
+///
+/// ```rust
+/// use up_rust::{Sink, Source, UMessageBuilder, UUri};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let origin = Source(UUri::try_from("//my-vehicle/4210/5/F20B")?);
+/// let destination = Sink(UUri::try_from("//my-cloud/CCDD/2/0")?);
+/// assert!(UMessageBuilder::notification(origin, destination).build().is_ok());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// A `Source` cannot be used where a `Sink` is required:
+///
+/// ```compile_fail
+/// use up_rust::{Source, UMessageBuilder, UUri};
+///
+/// let origin = Source(UUri::try_from("//my-vehicle/4210/5/F20B").unwrap());
+/// let destination = Source(UUri::try_from("//my-cloud/CCDD/2/0").unwrap());
+/// let builder = UMessageBuilder::notification(origin, destination);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sink(pub UUri);
+
+impl From<UUri> for Sink {
+    fn from(uri: UUri) -> Self {
+        Sink(uri)
+    }
+}
+
+impl From<Sink> for UUri {
+    fn from(sink: Sink) -> Self {
+        sink.0
+    }
+}
+
 /// A builder for creating [`UMessage`]s.
 ///
 /// Messages are being used by a uEntity to inform other entities about the occurrence of events
@@ -123,12 +188,15 @@ impl UMessageBuilder {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn notification(origin: UUri, destination: UUri) -> UMessageBuilder {
+    pub fn notification<O: Into<Source>, D: Into<Sink>>(
+        origin: O,
+        destination: D,
+    ) -> UMessageBuilder {
         UMessageBuilder {
             validator: Box::new(NotificationValidator),
             message_type: UMessageType::UMESSAGE_TYPE_NOTIFICATION,
-            source: Some(origin),
-            sink: Some(destination),
+            source: Some(origin.into().0),
+            sink: Some(destination.into().0),
             ..Default::default()
         }
     }
@@ -165,12 +233,16 @@ impl UMessageBuilder {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn request(method_to_invoke: UUri, reply_to_address: UUri, ttl: u32) -> UMessageBuilder {
+    pub fn request<M: Into<Sink>, R: Into<Source>>(
+        method_to_invoke: M,
+        reply_to_address: R,
+        ttl: u32,
+    ) -> UMessageBuilder {
         UMessageBuilder {
             validator: Box::new(RequestValidator),
             message_type: UMessageType::UMESSAGE_TYPE_REQUEST,
-            source: Some(reply_to_address),
-            sink: Some(method_to_invoke),
+            source: Some(reply_to_address.into().0),
+            sink: Some(method_to_invoke.into().0),
             ttl: Some(ttl),
             priority: UPriority::UPRIORITY_CS4,
             ..Default::default()
@@ -212,22 +284,75 @@ impl UMessageBuilder {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn response(
-        reply_to_address: UUri,
+    pub fn response<R: Into<Sink>, M: Into<Source>>(
+        reply_to_address: R,
         request_id: UUID,
-        invoked_method: UUri,
+        invoked_method: M,
     ) -> UMessageBuilder {
         UMessageBuilder {
             validator: Box::new(ResponseValidator),
             message_type: UMessageType::UMESSAGE_TYPE_RESPONSE,
-            source: Some(invoked_method),
-            sink: Some(reply_to_address),
+            source: Some(invoked_method.into().0),
+            sink: Some(reply_to_address.into().0),
             request_id: Some(request_id),
             priority: UPriority::UPRIORITY_CS4,
             ..Default::default()
         }
     }
 
+    /// Gets a builder for creating RPC *response* messages, verifying the request identifier.
+    ///
+    /// This function works like [`UMessageBuilder::response`] but fails early if the given request
+    /// identifier is not a valid uProtocol UUID or if the reply-to address is empty, instead of
+    /// letting the `build` functions or the recipient fail later on.
+    ///
+    /// # Arguments
+    ///
+    /// * `reply_to_address` - The URI that the sender of the request expects to receive the response message at.
+    /// * `request_id` - The identifier of the request that this is the response to.
+    /// * `invoked_method` - The URI identifying the method that has been invoked and which the created message is
+    ///                      the outcome of.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request identifier is not a [valid uProtocol UUID](`UUID::is_uprotocol_uuid`)
+    /// or if the reply-to address equals [`UUri::default`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{UMessageBuilder, UUIDBuilder, UUri, UUID};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let invoked_method = UUri::try_from("//my-vehicle/4210/5/64AB")?;
+    /// let reply_to_address = UUri::try_from("//my-cloud/BA4C/1/0")?;
+    /// assert!(UMessageBuilder::response_checked(reply_to_address.clone(), UUID::default(), invoked_method.clone()).is_err());
+    /// assert!(UMessageBuilder::response_checked(UUri::default(), UUIDBuilder::build(), invoked_method.clone()).is_err());
+    ///
+    /// let builder = UMessageBuilder::response_checked(reply_to_address, UUIDBuilder::build(), invoked_method)?;
+    /// assert!(builder.build().is_ok());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn response_checked<R: Into<Sink>, M: Into<Source>>(
+        reply_to_address: R,
+        request_id: UUID,
+        invoked_method: M,
+    ) -> Result<UMessageBuilder, UMessageError> {
+        if !request_id.is_uprotocol_uuid() {
+            return Err(UMessageError::from(UAttributesError::validation_error(
+                "Request ID is not a valid uProtocol UUID",
+            )));
+        }
+        let reply_to_address = reply_to_address.into();
+        if reply_to_address.0 == UUri::default() {
+            return Err(UMessageError::from(UAttributesError::validation_error(
+                "Reply-to address must not be empty",
+            )));
+        }
+        Ok(Self::response(reply_to_address, request_id, invoked_method))
+    }
+
     /// Gets a builder for creating RPC *response* messages in reply to a *request*.
     ///
     /// A response message is used to send the outcome of processing a request message
@@ -412,6 +537,74 @@ impl UMessageBuilder {
         self
     }
 
+    /// Sets the message's time-to-live based on an absolute deadline.
+    ///
+    /// If a message ID has been set explicitly using [`UMessageBuilder::with_message_id`], the
+    /// time-to-live is determined relative to the creation time contained in that ID. Otherwise,
+    /// the time-to-live is determined relative to the current time and each message built
+    /// afterwards still gets a new message ID.
+    ///
+    /// The time-to-live is capped at `u32::MAX` milliseconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `deadline` - The point in time at which the message expires.
+    ///
+    /// # Returns
+    ///
+    /// The builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the deadline is not at least one millisecond after the message's creation
+    /// time or the current time respectively. The builder is left unchanged in this case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::{Duration, SystemTime};
+    /// use up_rust::{UMessageBuilder, UUri};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let method_to_invoke = UUri::try_from("//my-vehicle/4210/5/64AB")?;
+    /// let reply_to_address = UUri::try_from("//my-cloud/BA4C/1/0")?;
+    /// let deadline = SystemTime::now() + Duration::from_secs(10);
+    /// let message = UMessageBuilder::request(method_to_invoke, reply_to_address, 1000)
+    ///                     .with_deadline(deadline)?
+    ///                     .build()?;
+    /// assert!(message.attributes.ttl.is_some_and(|ttl| ttl > 9_000 && ttl <= 10_000));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_deadline(
+        &mut self,
+        deadline: SystemTime,
+    ) -> Result<&mut UMessageBuilder, UMessageError> {
+        let creation_time = match self.message_id.as_ref() {
+            Some(message_id) => message_id.get_time().map(u128::from).ok_or_else(|| {
+                UAttributesError::validation_error("Message ID does not contain a creation time")
+            })?,
+            None => SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_millis())
+                .unwrap_or_default(),
+        };
+        let deadline_millis = deadline
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis())
+            .unwrap_or_default();
+        match deadline_millis.checked_sub(creation_time) {
+            Some(ttl) if ttl > 0 => {
+                self.ttl = Some(u32::try_from(ttl).unwrap_or(u32::MAX));
+                Ok(self)
+            }
+            _ => Err(UAttributesError::validation_error(
+                "Deadline must be later than the message's creation time",
+            )
+            .into()),
+        }
+    }
+
     /// Sets the message's authorization token used for TAP.
     ///
     /// # Arguments
@@ -486,6 +679,9 @@ impl UMessageBuilder {
 
     /// Sets the message's communication status.
     ///
+    /// Prefer [`UMessageBuilder::with_comm_status_code`], which only accepts valid status codes.
+    /// This function is intended for passing on status codes as received on the wire.
+    ///
     /// # Arguments
     ///
     /// * `comm_status` - The status.
@@ -524,6 +720,40 @@ impl UMessageBuilder {
         self
     }
 
+    /// Sets the message's communication status.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The status code.
+    ///
+    /// # Returns
+    ///
+    /// The builder.
+    ///
+    /// # Panics
+    ///
+    /// * if the message is not an RPC response message
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{UCode, UMessageBuilder, UUIDBuilder, UUri};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let invoked_method = UUri::try_from("//my-vehicle/4210/5/64AB")?;
+    /// let reply_to_address = UUri::try_from("//my-cloud/BA4C/1/0")?;
+    /// let request_msg_id = UUIDBuilder::build();
+    /// let message = UMessageBuilder::response(reply_to_address, request_msg_id, invoked_method)
+    ///                     .with_comm_status_code(UCode::NOT_FOUND)
+    ///                     .build()?;
+    /// assert_eq!(message.attributes.commstatus, Some(UCode::NOT_FOUND.into()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_comm_status_code(&mut self, code: UCode) -> &mut UMessageBuilder {
+        self.with_comm_status(code.value())
+    }
+
     /// Creates the message based on the builder's state.
     ///
     /// # Returns
@@ -755,6 +985,8 @@ impl UMessageBuilder {
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use crate::{UCode, UUIDBuilder};
 
     use super::*;
@@ -831,6 +1063,185 @@ mod tests {
         }
     }
 
+    #[test_case(UCode::OK; "for OK")]
+    #[test_case(UCode::CANCELLED; "for CANCELLED")]
+    #[test_case(UCode::UNAUTHENTICATED; "for UNAUTHENTICATED")]
+    fn test_with_comm_status_code_stores_code_value(code: UCode) {
+        let method_to_invoke = UUri::try_from(METHOD_TO_INVOKE)
+            .expect("should have been able to create destination UUri");
+        let reply_to_address = UUri::try_from(REPLY_TO_ADDRESS)
+            .expect("should have been able to create reply-to UUri");
+        let message =
+            UMessageBuilder::response(reply_to_address, UUIDBuilder::build(), method_to_invoke)
+                .with_comm_status_code(code)
+                .build()
+                .expect("should have been able to create message");
+        assert_eq!(
+            message.attributes.commstatus.map(|status| status.value()),
+            Some(code.value())
+        );
+        assert!(ResponseValidator.validate(&message.attributes).is_ok());
+    }
+
+    #[test]
+    fn test_with_deadline_sets_ttl_relative_to_message_id() {
+        let method_to_invoke = UUri::try_from(METHOD_TO_INVOKE)
+            .expect("should have been able to create destination UUri");
+        let reply_to_address = UUri::try_from(REPLY_TO_ADDRESS)
+            .expect("should have been able to create reply-to UUri");
+        let message_id = UUIDBuilder::build();
+        let creation_time = UNIX_EPOCH + Duration::from_millis(message_id.get_time().unwrap());
+
+        let message = UMessageBuilder::request(method_to_invoke, reply_to_address, 1000)
+            .with_message_id(message_id)
+            .with_deadline(creation_time + Duration::from_millis(3_500))
+            .expect("should have been able to set deadline")
+            .build()
+            .expect("should have been able to create message");
+        assert_eq!(message.attributes.ttl, Some(3_500));
+    }
+
+    #[test]
+    fn test_with_deadline_creates_new_message_id_for_each_message() {
+        let method_to_invoke = UUri::try_from(METHOD_TO_INVOKE)
+            .expect("should have been able to create destination UUri");
+        let reply_to_address = UUri::try_from(REPLY_TO_ADDRESS)
+            .expect("should have been able to create reply-to UUri");
+        let mut builder = UMessageBuilder::request(method_to_invoke, reply_to_address, 1000);
+        builder
+            .with_deadline(SystemTime::now() + Duration::from_secs(10))
+            .expect("should have been able to set deadline");
+
+        let message = builder
+            .build()
+            .expect("should have been able to build message");
+        let other_message = builder
+            .build()
+            .expect("should have been able to build message");
+        assert_ne!(message.attributes.id, other_message.attributes.id);
+        assert!(message
+            .attributes
+            .ttl
+            .is_some_and(|ttl| ttl > 9_000 && ttl <= 10_000));
+        assert_eq!(message.attributes.ttl, other_message.attributes.ttl);
+    }
+
+    #[test]
+    fn test_with_deadline_caps_ttl_at_max_value() {
+        let method_to_invoke = UUri::try_from(METHOD_TO_INVOKE)
+            .expect("should have been able to create destination UUri");
+        let reply_to_address = UUri::try_from(REPLY_TO_ADDRESS)
+            .expect("should have been able to create reply-to UUri");
+        let message_id = UUIDBuilder::build();
+        let creation_time = UNIX_EPOCH + Duration::from_millis(message_id.get_time().unwrap());
+
+        let message = UMessageBuilder::request(method_to_invoke, reply_to_address, 1000)
+            .with_message_id(message_id)
+            .with_deadline(creation_time + Duration::from_millis(u64::from(u32::MAX) + 1))
+            .expect("should have been able to set deadline")
+            .build()
+            .expect("should have been able to create message");
+        assert_eq!(message.attributes.ttl, Some(u32::MAX));
+    }
+
+    #[test]
+    fn test_with_deadline_fails_for_past_deadline() {
+        let method_to_invoke = UUri::try_from(METHOD_TO_INVOKE)
+            .expect("should have been able to create destination UUri");
+        let reply_to_address = UUri::try_from(REPLY_TO_ADDRESS)
+            .expect("should have been able to create reply-to UUri");
+        let mut builder = UMessageBuilder::request(method_to_invoke, reply_to_address, 1000);
+        assert!(builder
+            .with_deadline(SystemTime::now() - Duration::from_secs(1))
+            .is_err());
+        assert!(builder.with_deadline(UNIX_EPOCH).is_err());
+    }
+
+    #[test]
+    fn test_with_deadline_leaves_builder_unchanged_for_past_deadline() {
+        let method_to_invoke = UUri::try_from(METHOD_TO_INVOKE)
+            .expect("should have been able to create destination UUri");
+        let reply_to_address = UUri::try_from(REPLY_TO_ADDRESS)
+            .expect("should have been able to create reply-to UUri");
+        let mut builder = UMessageBuilder::request(method_to_invoke, reply_to_address, 1000);
+        assert!(builder
+            .with_deadline(SystemTime::now() - Duration::from_secs(1))
+            .is_err());
+
+        let message = builder
+            .build()
+            .expect("should have been able to build message");
+        let other_message = builder
+            .build()
+            .expect("should have been able to build message");
+        assert_ne!(message.attributes.id, other_message.attributes.id);
+        assert_eq!(message.attributes.ttl, Some(1000));
+        assert_eq!(other_message.attributes.ttl, Some(1000));
+    }
+
+    #[test]
+    fn test_builder_accepts_typed_source_and_sink() {
+        let method_to_invoke = UUri::try_from(METHOD_TO_INVOKE)
+            .expect("should have been able to create destination UUri");
+        let reply_to_address = UUri::try_from(REPLY_TO_ADDRESS)
+            .expect("should have been able to create reply-to UUri");
+        let message = UMessageBuilder::request(
+            Sink(method_to_invoke.clone()),
+            Source(reply_to_address.clone()),
+            5000,
+        )
+        .build()
+        .expect("should have been able to build message");
+        assert_eq!(message.attributes.sink, Some(method_to_invoke).into());
+        assert_eq!(message.attributes.source, Some(reply_to_address).into());
+    }
+
+    #[test]
+    fn test_response_checked_fails_for_invalid_request_id() {
+        let method_to_invoke = UUri::try_from(METHOD_TO_INVOKE)
+            .expect("should have been able to create destination UUri");
+        let reply_to_address = UUri::try_from(REPLY_TO_ADDRESS)
+            .expect("should have been able to create reply-to UUri");
+        assert!(UMessageBuilder::response_checked(
+            reply_to_address,
+            UUID::default(),
+            method_to_invoke
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_response_checked_fails_for_default_sink() {
+        let method_to_invoke = UUri::try_from(METHOD_TO_INVOKE)
+            .expect("should have been able to create destination UUri");
+        assert!(matches!(
+            UMessageBuilder::response_checked(
+                UUri::default(),
+                UUIDBuilder::build(),
+                method_to_invoke
+            ),
+            Err(UMessageError::AttributesValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_response_checked_succeeds_for_valid_request_id() {
+        let request_id = UUIDBuilder::build();
+        let method_to_invoke = UUri::try_from(METHOD_TO_INVOKE)
+            .expect("should have been able to create destination UUri");
+        let reply_to_address = UUri::try_from(REPLY_TO_ADDRESS)
+            .expect("should have been able to create reply-to UUri");
+        let message = UMessageBuilder::response_checked(
+            reply_to_address,
+            request_id.clone(),
+            method_to_invoke,
+        )
+        .expect("should have been able to create builder")
+        .build()
+        .expect("should have been able to build message");
+        assert_eq!(message.attributes.reqid, Some(request_id).into());
+    }
+
     #[test]
     fn test_build_supports_repeated_invocation() {
         let topic = UUri::try_from(TOPIC).expect("should have been able to create UUri");
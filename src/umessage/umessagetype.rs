@@ -11,7 +11,7 @@
  * SPDX-License-Identifier: Apache-2.0
  ********************************************************************************/
 
-use protobuf::EnumFull;
+use protobuf::{Enum, EnumFull};
 
 use crate::uattributes::UAttributesError;
 pub use crate::up_core_api::uattributes::UMessageType;
@@ -59,6 +59,40 @@ impl UMessageType {
                 UAttributesError::parsing_error(format!("unknown message type: {}", type_string))
             })
     }
+
+    /// Gets the message type for a CloudEvent type name, tolerating deviations from the
+    /// exact spelling.
+    ///
+    /// In contrast to [`UMessageType::try_from_cloudevent_type`], which should be used for
+    /// validation, the given name is matched case-insensitively and may omit the version suffix,
+    /// e.g. `PUB.V1` and `pub` both match the CloudEvent type name `pub.v1`.
+    ///
+    /// # Returns
+    ///
+    /// The message type or `None` if the given name does not match any of the supported message types.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UMessageType;
+    ///
+    /// assert_eq!(
+    ///     UMessageType::from_ce_type_lenient("PUB.V1"),
+    ///     Some(UMessageType::UMESSAGE_TYPE_PUBLISH)
+    /// );
+    /// assert!(UMessageType::try_from_cloudevent_type("PUB.V1").is_err());
+    /// ```
+    pub fn from_ce_type_lenient(value: &str) -> Option<Self> {
+        let type_string = value.to_lowercase();
+        Self::VALUES.iter().copied().find(|message_type| {
+            let ce_type = message_type.to_cloudevent_type();
+            !ce_type.is_empty()
+                && (ce_type == type_string
+                    || ce_type
+                        .split_once('.')
+                        .is_some_and(|(name, _version)| name == type_string))
+        })
+    }
 }
 
 #[cfg(test)]
@@ -80,6 +114,29 @@ mod tests {
         assert_eq!(message_type.to_cloudevent_type(), expected_ce_name);
     }
 
+    #[test_case("PUB.V1", Some(UMessageType::UMESSAGE_TYPE_PUBLISH); "succeeds for upper case name")]
+    #[test_case("pub", Some(UMessageType::UMESSAGE_TYPE_PUBLISH); "succeeds for name without version")]
+    #[test_case("Req", Some(UMessageType::UMESSAGE_TYPE_REQUEST); "succeeds for mixed case name without version")]
+    #[test_case(TYPE_RESPONSE, Some(UMessageType::UMESSAGE_TYPE_RESPONSE); "succeeds for exact name")]
+    #[test_case("pub.v2", None; "fails for other version")]
+    #[test_case("foo", None; "fails for unknown type")]
+    #[test_case("", None; "fails for empty name")]
+    fn test_from_ce_type_lenient(
+        cloudevent_type: &str,
+        expected_message_type: Option<UMessageType>,
+    ) {
+        assert_eq!(
+            UMessageType::from_ce_type_lenient(cloudevent_type),
+            expected_message_type
+        );
+    }
+
+    #[test_case("PUB.V1"; "for upper case name")]
+    #[test_case("pub"; "for name without version")]
+    fn test_try_from_cloudevent_type_is_strict(cloudevent_type: &str) {
+        assert!(UMessageType::try_from_cloudevent_type(cloudevent_type).is_err());
+    }
+
     #[test_case(TYPE_PUBLISH, Some(UMessageType::UMESSAGE_TYPE_PUBLISH); "succeeds for PUBLISH")]
     #[test_case(TYPE_NOTIFICATION, Some(UMessageType::UMESSAGE_TYPE_NOTIFICATION); "succeeds for NOTIFICATION")]
     #[test_case(TYPE_REQUEST, Some(UMessageType::UMESSAGE_TYPE_REQUEST); "succeeds for REQUEST")]
@@ -19,6 +19,11 @@ use std::str::FromStr;
 
 use uriparse::URIReference;
 
+use crate::{UMessageType, UriAttribute};
+
+mod uricache;
+pub use uricache::UriCache;
+
 pub use crate::up_core_api::uri::UUri;
 
 const WILDCARD_AUTHORITY: &str = "*";
@@ -29,6 +34,74 @@ const WILDCARD_RESOURCE_ID: u32 = 0x0000_FFFF;
 const RESOURCE_ID_RESPONSE: u32 = 0;
 const RESOURCE_ID_MIN_EVENT: u32 = 0x8000;
 
+/// The role that a UUri plays in the communication between uEntities.
+///
+/// The role is determined by the UUri's resource ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UriRole {
+    /// A topic that events are published to (resource ID >= 0x8000).
+    Topic,
+    /// A method that can be invoked via RPC (0 < resource ID < 0x8000).
+    RpcMethod,
+    /// The address that RPC responses and notifications are sent to (resource ID 0).
+    RpcResponse,
+}
+
+impl UriRole {
+    /// Determines the roles that a URI contained in a message's attributes may play.
+    ///
+    /// # Arguments
+    ///
+    /// * `message_type` - The type of message.
+    /// * `attribute` - The attribute containing the URI.
+    ///
+    /// # Returns
+    ///
+    /// The acceptable roles. The list is empty if the given type of message does not contain
+    /// a URI in the given attribute.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{UMessageType, UriAttribute, UriRole};
+    ///
+    /// assert_eq!(
+    ///     UriRole::expected_role_for(UMessageType::UMESSAGE_TYPE_REQUEST, UriAttribute::Sink),
+    ///     [UriRole::RpcMethod]
+    /// );
+    /// assert_eq!(
+    ///     UriRole::expected_role_for(
+    ///         UMessageType::UMESSAGE_TYPE_NOTIFICATION,
+    ///         UriAttribute::Source
+    ///     ),
+    ///     [UriRole::Topic, UriRole::RpcMethod]
+    /// );
+    /// assert!(
+    ///     UriRole::expected_role_for(UMessageType::UMESSAGE_TYPE_PUBLISH, UriAttribute::Sink)
+    ///         .is_empty()
+    /// );
+    /// ```
+    pub fn expected_role_for(
+        message_type: UMessageType,
+        attribute: UriAttribute,
+    ) -> &'static [UriRole] {
+        match (message_type, attribute) {
+            (UMessageType::UMESSAGE_TYPE_PUBLISH, UriAttribute::Source) => &[UriRole::Topic],
+            (UMessageType::UMESSAGE_TYPE_NOTIFICATION, UriAttribute::Source) => {
+                &[UriRole::Topic, UriRole::RpcMethod]
+            }
+            (UMessageType::UMESSAGE_TYPE_NOTIFICATION, UriAttribute::Sink) => {
+                &[UriRole::RpcResponse]
+            }
+            (UMessageType::UMESSAGE_TYPE_REQUEST, UriAttribute::Source) => &[UriRole::RpcResponse],
+            (UMessageType::UMESSAGE_TYPE_REQUEST, UriAttribute::Sink) => &[UriRole::RpcMethod],
+            (UMessageType::UMESSAGE_TYPE_RESPONSE, UriAttribute::Source) => &[UriRole::RpcMethod],
+            (UMessageType::UMESSAGE_TYPE_RESPONSE, UriAttribute::Sink) => &[UriRole::RpcResponse],
+            _ => &[],
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum UUriError {
     SerializationError(String),
@@ -155,12 +228,13 @@ impl FromStr for UUri {
                     ))
                 } else {
                     let auth_name = auth.host().to_string();
-                    if auth_name.len() <= 128 {
+                    if auth_name.len() <= UUri::MAX_AUTHORITY_NAME_LEN {
                         Ok(auth_name)
                     } else {
-                        Err(UUriError::serialization_error(
-                            "URI's authority name must not exceed 128 characters",
-                        ))
+                        Err(UUriError::serialization_error(format!(
+                            "URI's authority name must not exceed {} characters",
+                            UUri::MAX_AUTHORITY_NAME_LEN
+                        )))
                     }
                 }
             })?;
@@ -274,6 +348,10 @@ impl Hash for UUri {
 impl Eq for UUri {}
 
 impl UUri {
+    /// The maximum number of bytes that an authority name may consist of.
+    // [impl->dsn~authority-name-length~1]
+    pub const MAX_AUTHORITY_NAME_LEN: usize = 128;
+
     /// Serializes this UUri to a URI string.
     ///
     /// # Arguments
@@ -317,6 +395,310 @@ impl UUri {
         output
     }
 
+    /// Gets a short string representation of this UUri for display purposes, e.g. in log messages.
+    ///
+    /// The short form omits the scheme and the leading slashes and separates the resource ID from
+    /// the entity with a colon. It is not intended to be parsed back into a UUri,
+    /// use [`UUri::to_uri`] for that purpose instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// let uri = UUri::try_from("up://my-vehicle/A410/3/9C01").unwrap();
+    /// assert_eq!(uri.to_short_form(), "my-vehicle/A410/3:9C01");
+    ///
+    /// let local_uri = UUri::try_from("/A410/3/9C01").unwrap();
+    /// assert_eq!(local_uri.to_short_form(), "A410/3:9C01");
+    /// ```
+    pub fn to_short_form(&self) -> String {
+        let entity = format!(
+            "{:X}/{:X}:{:X}",
+            self.ue_id, self.ue_version_major, self.resource_id
+        );
+        if self.authority_name.is_empty() {
+            entity
+        } else {
+            format!("{}/{}", self.authority_name, entity)
+        }
+    }
+
+    /// Gets a canonical string representation of this UUri.
+    ///
+    /// The canonical representation is the output of [`UUri::to_uri`] (without scheme) with the
+    /// authority name converted to lowercase. URIs that refer to the same resource but have been
+    /// written differently, e.g. using a different case or leading zeros, result in the same string.
+    /// This makes the canonical representation suitable for use as a key in subscription stores.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// let uri = UUri::try_from("up://My-Vehicle/0a410/03/9c01").unwrap();
+    /// assert_eq!(uri.canonical_topic(), "//my-vehicle/A410/3/9C01");
+    /// ```
+    pub fn canonical_topic(&self) -> String {
+        let mut canonical_uri = self.clone();
+        canonical_uri.authority_name = self.authority_name.to_lowercase();
+        canonical_uri.to_uri(false)
+    }
+
+    /// Gets an identifier for this UUri that can safely be used as a file name.
+    ///
+    /// The identifier is the [canonical representation](`UUri::canonical_topic`) of this UUri
+    /// with all characters other than ASCII letters, digits, `-`, `_` and `.` being percent-encoded.
+    /// The identifier is therefore deterministic, does not contain any path separators and URIs
+    /// with different canonical representations result in different identifiers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// let uri = UUri::try_from("//My-Vehicle/A410/3/9C01").unwrap();
+    /// assert_eq!(uri.to_fs_safe_id(), "%2F%2Fmy-vehicle%2FA410%2F3%2F9C01");
+    /// ```
+    pub fn to_fs_safe_id(&self) -> String {
+        self.canonical_topic()
+            .bytes()
+            .map(|b| {
+                if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.') {
+                    (b as char).to_string()
+                } else {
+                    format!("%{:02X}", b)
+                }
+            })
+            .collect()
+    }
+
+    /// Gets this UUri's components as key/value pairs, e.g. for tagging tracing spans.
+    ///
+    /// # Returns
+    ///
+    /// The pairs `up.authority` (only if the authority is not empty), `up.entity` (entity ID and
+    /// major version) and `up.resource`. Numeric values are encoded in the same way as in
+    /// [`UUri::to_uri`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// let uuri = UUri::try_from("//VIN.vehicles/800A/2/1A50").unwrap();
+    /// assert_eq!(
+    ///     uuri.to_attribute_pairs(),
+    ///     vec![
+    ///         ("up.authority", "VIN.vehicles".to_string()),
+    ///         ("up.entity", "800A/2".to_string()),
+    ///         ("up.resource", "1A50".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn to_attribute_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = Vec::with_capacity(3);
+        if !self.authority_name.is_empty() {
+            pairs.push(("up.authority", self.authority_name.clone()));
+        }
+        pairs.push((
+            "up.entity",
+            format!("{:X}/{:X}", self.ue_id, self.ue_version_major),
+        ));
+        pairs.push(("up.resource", format!("{:X}", self.resource_id)));
+        pairs
+    }
+
+    /// Checks if this UUri refers to a resource on the local device.
+    ///
+    /// # Returns
+    ///
+    /// `true` if this UUri does not contain an authority.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// assert!(UUri::try_from("/800A/2/1A50").unwrap().is_local());
+    /// assert!(!UUri::try_from("//my-vehicle/800A/2/1A50").unwrap().is_local());
+    /// ```
+    pub fn is_local(&self) -> bool {
+        self.authority_name.is_empty()
+    }
+
+    /// Checks if this UUri refers to a resource on a given device.
+    ///
+    /// # Arguments
+    ///
+    /// * `local_authority` - The name of the authority that the device is known by.
+    ///
+    /// # Returns
+    ///
+    /// `true` if this UUri [is local](Self::is_local) or its authority is equal to the given authority.
+    /// Authority names are compared case-insensitively.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// assert!(UUri::try_from("/800A/2/1A50").unwrap().is_local_to("my-vehicle"));
+    /// assert!(UUri::try_from("//my-vehicle/800A/2/1A50").unwrap().is_local_to("my-vehicle"));
+    /// assert!(!UUri::try_from("//other-vehicle/800A/2/1A50").unwrap().is_local_to("my-vehicle"));
+    /// assert!(UUri::try_from("//my-vehicle/800A/2/1A50").unwrap().is_local_to("MY-VEHICLE"));
+    /// ```
+    pub fn is_local_to(&self, local_authority: &str) -> bool {
+        self.is_local() || self.authority_name.eq_ignore_ascii_case(local_authority)
+    }
+
+    /// Resolves this UUri against a base authority.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_authority` - The name of the authority to use if this UUri is local.
+    ///
+    /// # Returns
+    ///
+    /// A copy of this UUri with its authority set to the base authority if this UUri
+    /// does not contain an authority, or an unchanged copy of this UUri otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// let local_uri = UUri::try_from("/800A/2/1A50").unwrap();
+    /// assert_eq!(local_uri.resolve_against("my-vehicle").to_uri(false), "//my-vehicle/800A/2/1A50");
+    ///
+    /// let remote_uri = UUri::try_from("//other-vehicle/800A/2/1A50").unwrap();
+    /// assert_eq!(remote_uri.resolve_against("my-vehicle"), remote_uri);
+    /// ```
+    pub fn resolve_against(&self, base_authority: &str) -> UUri {
+        let mut resolved_uri = self.clone();
+        if resolved_uri.authority_name.is_empty() {
+            resolved_uri.authority_name = base_authority.to_string();
+        }
+        resolved_uri
+    }
+
+    /// Splits a list of URIs into URIs referring to resources on a given device and
+    /// URIs referring to resources on other devices.
+    ///
+    /// # Arguments
+    ///
+    /// * `uris` - The URIs to split.
+    /// * `local_authority` - The name of the authority that the device is known by.
+    ///
+    /// # Returns
+    ///
+    /// The URIs that are [local to](Self::is_local_to) the given authority and the remaining URIs,
+    /// both in their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let uris = vec![
+    ///     UUri::try_from("//other-vehicle/800A/2/1A50")?,
+    ///     UUri::try_from("/800A/2/1A50")?,
+    ///     UUri::try_from("//my-vehicle/800A/2/1A50")?,
+    /// ];
+    /// let (local, remote) = UUri::partition_local_remote(&uris, "my-vehicle");
+    /// assert_eq!(local, vec![&uris[1], &uris[2]]);
+    /// assert_eq!(remote, vec![&uris[0]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn partition_local_remote<'a>(
+        uris: &'a [UUri],
+        local_authority: &str,
+    ) -> (Vec<&'a UUri>, Vec<&'a UUri>) {
+        uris.iter()
+            .partition(|uri| uri.is_local_to(local_authority))
+    }
+
+    /// Gets the names of all remote authorities that a list of URIs refers to.
+    ///
+    /// This is useful for determining the devices that a gateway needs to connect to.
+    ///
+    /// # Arguments
+    ///
+    /// * `uris` - The URIs to get the authorities of.
+    ///
+    /// # Returns
+    ///
+    /// The authority names in the order of their first occurrence. Authority names are compared
+    /// case-insensitively, i.e. only the first occurrence of names differing in case only is included.
+    /// [Local](Self::is_local) URIs and URIs with the wildcard authority (`*`) are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let uris = vec![
+    ///     UUri::try_from("//other-vehicle/800A/2/1A50")?,
+    ///     UUri::try_from("/800A/2/1A50")?,
+    ///     UUri::try_from("//my-cloud/800A/2/1A50")?,
+    ///     UUri::try_from("//other-vehicle/1B/1/0")?,
+    /// ];
+    /// assert_eq!(UUri::distinct_authorities(&uris), vec!["other-vehicle", "my-cloud"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn distinct_authorities(uris: &[UUri]) -> Vec<&str> {
+        let mut authorities: Vec<&str> = Vec::new();
+        for uri in uris {
+            let authority = uri.authority_name.as_str();
+            if !uri.is_local()
+                && authority != WILDCARD_AUTHORITY
+                && !authorities
+                    .iter()
+                    .any(|known_authority| known_authority.eq_ignore_ascii_case(authority))
+            {
+                authorities.push(authority);
+            }
+        }
+        authorities
+    }
+
+    /// Verifies that this UUri's authority name does not exceed the maximum length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the authority name consists of more than
+    /// [`UUri::MAX_AUTHORITY_NAME_LEN`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// let uri = UUri {
+    ///     authority_name: "a".repeat(UUri::MAX_AUTHORITY_NAME_LEN + 1),
+    ///     ue_id: 0x0000_2310,
+    ///     ue_version_major: 0x03,
+    ///     resource_id: 0xa000,
+    ///     ..Default::default()
+    /// };
+    /// assert!(uri.verify_authority_name().is_err());
+    /// ```
+    // [impl->dsn~authority-name-length~1]
+    pub fn verify_authority_name(&self) -> Result<(), UUriError> {
+        if self.authority_name.len() > Self::MAX_AUTHORITY_NAME_LEN {
+            Err(UUriError::validation_error(format!(
+                "Authority name must not exceed {} bytes",
+                Self::MAX_AUTHORITY_NAME_LEN
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
     /// Verifies that this UUri does not contain any wildcards.
     ///
     /// # Errors
@@ -386,8 +768,9 @@ impl UUri {
     ///
     /// # Errors
     ///
-    /// Returns an error if [`Self::is_rpc_method`] fails or
-    /// the UUri [contains any wildcards](Self::verify_no_wildcards).
+    /// Returns an error if [`Self::is_rpc_method`] fails,
+    /// the UUri [contains any wildcards](Self::verify_no_wildcards) or
+    /// the authority name [is too long](Self::verify_authority_name).
     ///
     /// # Examples
     ///
@@ -413,7 +796,8 @@ impl UUri {
                 RESOURCE_ID_RESPONSE, RESOURCE_ID_MIN_EVENT
             )))
         } else {
-            self.verify_no_wildcards()
+            self.verify_authority_name()
+                .and_then(|_| self.verify_no_wildcards())
         }
     }
 
@@ -459,8 +843,9 @@ impl UUri {
     ///
     /// # Errors
     ///
-    /// Returns an error if [`Self::is_rpc_response`] fails or
-    /// the UUri [contains any wildcards](Self::verify_no_wildcards).
+    /// Returns an error if [`Self::is_rpc_response`] fails,
+    /// the UUri [contains any wildcards](Self::verify_no_wildcards) or
+    /// the authority name [is too long](Self::verify_authority_name).
     ///
     /// # Examples
     ///
@@ -480,7 +865,8 @@ impl UUri {
                 RESOURCE_ID_RESPONSE
             )))
         } else {
-            self.verify_no_wildcards()
+            self.verify_authority_name()
+                .and_then(|_| self.verify_no_wildcards())
         }
     }
 
@@ -507,8 +893,9 @@ impl UUri {
     ///
     /// # Errors
     ///
-    /// Returns an error if [`Self::is_event`] fails or
-    /// the UUri [contains any wildcards](Self::verify_no_wildcards).
+    /// Returns an error if [`Self::is_event`] fails,
+    /// the UUri [contains any wildcards](Self::verify_no_wildcards) or
+    /// the authority name [is too long](Self::verify_authority_name).
     ///
     /// # Examples
     ///
@@ -528,7 +915,67 @@ impl UUri {
                 RESOURCE_ID_MIN_EVENT
             )))
         } else {
-            self.verify_no_wildcards()
+            self.verify_authority_name()
+                .and_then(|_| self.verify_no_wildcards())
+        }
+    }
+
+    /// Creates a URI for invoking an RPC method of a uEntity.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - A URI referring to the uEntity that provides the method. The URI's resource ID is ignored.
+    /// * `method_id` - The method's resource ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resulting URI is not a valid RPC method URI according to
+    /// [`UUri::verify_rpc_method`], e.g. because the method ID is not in the range reserved for methods.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUri;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let entity = UUri::try_from("//my-vehicle/A410/3/0")?;
+    /// let method = UUri::rpc_method_uri(&entity, 0x1C01)?;
+    /// assert_eq!(method.to_uri(false), "//my-vehicle/A410/3/1C01");
+    /// assert!(UUri::rpc_method_uri(&entity, 0x9C01).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rpc_method_uri(entity: &UUri, method_id: u16) -> Result<UUri, UUriError> {
+        let method = UUri {
+            resource_id: u32::from(method_id),
+            ..entity.clone()
+        };
+        method.verify_rpc_method().map(|_| method)
+    }
+
+    /// Determines the role that this UUri plays in the communication between uEntities.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{UriRole, UUri};
+    ///
+    /// let uri = UUri::try_from("//my-vehicle/A410/3/9C01").unwrap();
+    /// assert_eq!(uri.classify(), UriRole::Topic);
+    ///
+    /// let uri = UUri::try_from("//my-vehicle/A410/3/1C01").unwrap();
+    /// assert_eq!(uri.classify(), UriRole::RpcMethod);
+    ///
+    /// let uri = UUri::try_from("//my-vehicle/A410/3/0").unwrap();
+    /// assert_eq!(uri.classify(), UriRole::RpcResponse);
+    /// ```
+    pub fn classify(&self) -> UriRole {
+        if self.is_rpc_response() {
+            UriRole::RpcResponse
+        } else if self.is_rpc_method() {
+            UriRole::RpcMethod
+        } else {
+            UriRole::Topic
         }
     }
 
@@ -587,6 +1034,7 @@ impl UUri {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{UAttributes, UAttributesValidator, UAttributesValidators};
     use protobuf::Message;
     use test_case::test_case;
 
@@ -710,6 +1158,306 @@ mod tests {
         assert!(UUri::from_str(&uri).is_err());
     }
 
+    // [utest->dsn~authority-name-length~1]
+    #[test]
+    fn test_from_str_succeeds_for_authority_of_max_length() {
+        let host_name = "a".repeat(UUri::MAX_AUTHORITY_NAME_LEN);
+        let uri = format!("//{}/A100/1/6501", host_name);
+        let uuri = UUri::from_str(&uri).expect("should have been able to create UUri");
+        assert!(uuri.verify_authority_name().is_ok());
+    }
+
+    // [utest->dsn~authority-name-length~1]
+    #[test]
+    fn test_verify_authority_name_fails_for_authority_exceeding_max_length() {
+        let uuri = UUri {
+            authority_name: "a".repeat(UUri::MAX_AUTHORITY_NAME_LEN + 1),
+            ue_id: 0xA100,
+            ue_version_major: 0x01,
+            resource_id: 0x6501,
+            ..Default::default()
+        };
+        assert!(uuri.verify_authority_name().is_err());
+    }
+
+    // [utest->dsn~authority-name-length~1]
+    #[test]
+    fn test_authority_exceeding_max_length_is_rejected() {
+        let host_name = "a".repeat(UUri::MAX_AUTHORITY_NAME_LEN + 1);
+        let uri = format!("//{}/A100/1/8001", host_name);
+        assert!(UUri::from_str(&uri).is_err());
+        assert!(UUri::try_from(uri.as_str()).is_err());
+        assert!(uri.parse::<UUri>().is_err());
+
+        let uuri = UUri {
+            authority_name: host_name,
+            ue_id: 0xA100,
+            ue_version_major: 0x01,
+            ..Default::default()
+        };
+        let event = UUri {
+            resource_id: 0x8001,
+            ..uuri.clone()
+        };
+        let method = UUri {
+            resource_id: 0x0001,
+            ..uuri.clone()
+        };
+        let response = UUri {
+            resource_id: 0x0000,
+            ..uuri
+        };
+        assert!(event.verify_no_wildcards().is_ok());
+        assert!(event.verify_event().is_err());
+        assert!(method.verify_rpc_method().is_err());
+        assert!(response.verify_rpc_response().is_err());
+    }
+
+    #[test_case("//my-vehicle/A410/3/9C01", "up://MY-VEHICLE/a410/3/9c01"; "for different case")]
+    #[test_case("//my-vehicle/A410/3/9C01", "//my-vehicle/000A410/03/09C01"; "for leading zeros")]
+    #[test_case("/A410/3/9C01", "/a410/03/9c01"; "for local URIs")]
+    fn test_canonical_topic(uri: &str, equivalent_uri: &str) {
+        let uuri = UUri::try_from(uri).expect("should have been able to create UUri");
+        let equivalent_uuri =
+            UUri::try_from(equivalent_uri).expect("should have been able to create UUri");
+        assert_eq!(uuri.canonical_topic(), uri);
+        assert_eq!(equivalent_uuri.canonical_topic(), uri);
+    }
+
+    #[test_case("//my-vehicle/A410/3/0", 0x0001; "for remote entity")]
+    #[test_case("/A410/3/0", 0x7FFF; "for local entity")]
+    #[test_case("//my-vehicle/A410/3/9C01", 0x1C01; "for entity URI with resource ID")]
+    fn test_rpc_method_uri(entity: &str, method_id: u16) {
+        let entity = UUri::try_from(entity).unwrap();
+        let method = UUri::rpc_method_uri(&entity, method_id).unwrap();
+        assert!(method.verify_rpc_method().is_ok());
+        assert_eq!(method.authority_name, entity.authority_name);
+        assert_eq!(method.ue_id, entity.ue_id);
+        assert_eq!(method.ue_version_major, entity.ue_version_major);
+        assert_eq!(method.resource_id, u32::from(method_id));
+        assert_eq!(UUri::try_from(method.to_uri(true)).unwrap(), method);
+    }
+
+    #[test_case(0x0000; "for RPC response resource ID")]
+    #[test_case(0x8000; "for event resource ID")]
+    #[test_case(0xFFFF; "for wildcard resource ID")]
+    fn test_rpc_method_uri_fails_for_invalid_method_id(method_id: u16) {
+        let entity = UUri::try_from("//my-vehicle/A410/3/0").unwrap();
+        assert!(UUri::rpc_method_uri(&entity, method_id).is_err());
+    }
+
+    #[test_case("up://my-vehicle/A410/3/9C01", "my-vehicle/A410/3:9C01"; "for remote URI with scheme")]
+    #[test_case("//my-vehicle/A410/3/0", "my-vehicle/A410/3:0"; "for remote RPC response URI")]
+    #[test_case("/A410/3/9C01", "A410/3:9C01"; "for local URI")]
+    fn test_to_short_form(uri: &str, expected_short_form: &str) {
+        let uuri = UUri::try_from(uri).unwrap();
+        let short_form = uuri.to_short_form();
+        assert_eq!(short_form, expected_short_form);
+        assert!(!short_form.starts_with('/'));
+        assert!(UUri::try_from(short_form).is_err());
+    }
+
+    #[test_case(UMessageType::UMESSAGE_TYPE_PUBLISH, UriAttribute::Source, &[UriRole::Topic]; "for publish source")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_PUBLISH, UriAttribute::Sink, &[]; "for publish sink")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_NOTIFICATION, UriAttribute::Source, &[UriRole::Topic, UriRole::RpcMethod]; "for notification source")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_NOTIFICATION, UriAttribute::Sink, &[UriRole::RpcResponse]; "for notification sink")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_REQUEST, UriAttribute::Source, &[UriRole::RpcResponse]; "for request source")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_REQUEST, UriAttribute::Sink, &[UriRole::RpcMethod]; "for request sink")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_RESPONSE, UriAttribute::Source, &[UriRole::RpcMethod]; "for response source")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_RESPONSE, UriAttribute::Sink, &[UriRole::RpcResponse]; "for response sink")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_UNSPECIFIED, UriAttribute::Source, &[]; "for unspecified source")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_UNSPECIFIED, UriAttribute::Sink, &[]; "for unspecified sink")]
+    fn test_expected_role_for(
+        message_type: UMessageType,
+        attribute: UriAttribute,
+        expected_roles: &[UriRole],
+    ) {
+        assert_eq!(
+            UriRole::expected_role_for(message_type, attribute),
+            expected_roles
+        );
+    }
+
+    #[test_case(UMessageType::UMESSAGE_TYPE_PUBLISH; "for publish messages")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_NOTIFICATION; "for notification messages")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_REQUEST; "for request messages")]
+    #[test_case(UMessageType::UMESSAGE_TYPE_RESPONSE; "for response messages")]
+    fn test_expected_role_for_agrees_with_validator(message_type: UMessageType) {
+        let validator = UAttributesValidators::get_validator(message_type);
+        for uri in [
+            "//my-vehicle/A410/3/9C01",
+            "//my-vehicle/A410/3/1C01",
+            "//my-vehicle/A410/3/0",
+        ] {
+            let uri = UUri::try_from(uri).expect("should have been able to create UUri");
+            let role = uri.classify();
+
+            let attributes = UAttributes {
+                source: Some(uri.clone()).into(),
+                ..Default::default()
+            };
+            assert_eq!(
+                validator.validate_source(&attributes).is_ok(),
+                UriRole::expected_role_for(message_type, UriAttribute::Source).contains(&role),
+                "source URI with role {:?}",
+                role
+            );
+
+            let attributes = UAttributes {
+                sink: Some(uri).into(),
+                ..Default::default()
+            };
+            assert_eq!(
+                validator.validate_sink(&attributes).is_ok(),
+                UriRole::expected_role_for(message_type, UriAttribute::Sink).contains(&role),
+                "sink URI with role {:?}",
+                role
+            );
+        }
+    }
+
+    #[test_case("//my-vehicle/A410/3/9C01", "//my-vehicle/A410/3/9C02"; "for different resource IDs")]
+    #[test_case("//my-vehicle/A410/3/9C01", "/A410/3/9C01"; "for remote and local URIs")]
+    #[test_case("//my-vehicle/A410/3/9C01", "//my-vehicle.a/410/3/9C01"; "for differently split segments")]
+    fn test_to_fs_safe_id_differs_for_different_uris(uri: &str, other_uri: &str) {
+        let uuri = UUri::try_from(uri).expect("should have been able to create UUri");
+        let other_uuri = UUri::try_from(other_uri).expect("should have been able to create UUri");
+        let id = uuri.to_fs_safe_id();
+        let other_id = other_uuri.to_fs_safe_id();
+        assert_ne!(id, other_id);
+        for fs_safe_id in [id, other_id] {
+            assert!(!fs_safe_id.contains('/'));
+            assert!(!fs_safe_id.contains('\\'));
+        }
+    }
+
+    #[test]
+    fn test_to_fs_safe_id_is_deterministic() {
+        let uuri = UUri::try_from("up://MY-VEHICLE/a410/3/9c01").unwrap();
+        let equivalent_uuri = UUri::try_from("//my-vehicle/A410/03/9C01").unwrap();
+        assert_eq!(uuri.to_fs_safe_id(), equivalent_uuri.to_fs_safe_id());
+    }
+
+    #[test]
+    fn test_to_attribute_pairs() {
+        let remote_uri = UUri::try_from("//my-vehicle/1A4F/2/B392").unwrap();
+        assert_eq!(
+            remote_uri.to_attribute_pairs(),
+            vec![
+                ("up.authority", "my-vehicle".to_string()),
+                ("up.entity", "1A4F/2".to_string()),
+                ("up.resource", "B392".to_string()),
+            ]
+        );
+
+        let local_uri = UUri::try_from("/1A4F/2/B392").unwrap();
+        assert_eq!(
+            local_uri.to_attribute_pairs(),
+            vec![
+                ("up.entity", "1A4F/2".to_string()),
+                ("up.resource", "B392".to_string()),
+            ]
+        );
+    }
+
+    #[test_case("/A410/3/1003", true, true; "for local URI")]
+    #[test_case("//my-vehicle/A410/3/1003", false, true; "for URI with own authority")]
+    #[test_case("//other-vehicle/A410/3/1003", false, false; "for remote URI")]
+    fn test_is_local(uri: &str, expected_local: bool, expected_local_to: bool) {
+        let uuri = UUri::try_from(uri).expect("should have been able to create UUri");
+        assert_eq!(uuri.is_local(), expected_local);
+        assert_eq!(uuri.is_local_to("my-vehicle"), expected_local_to);
+    }
+
+    #[test_case("/A410/3/1003", "//my-vehicle/A410/3/1003"; "for local URI")]
+    #[test_case("//other-vehicle/A410/3/1003", "//other-vehicle/A410/3/1003"; "for remote URI")]
+    fn test_resolve_against(uri: &str, expected_uri: &str) {
+        let uuri = UUri::try_from(uri).expect("should have been able to create UUri");
+        let expected_uuri =
+            UUri::try_from(expected_uri).expect("should have been able to create UUri");
+        assert_eq!(uuri.resolve_against("my-vehicle"), expected_uuri);
+    }
+
+    #[test_case("//my-vehicle/A410/3/8000", UriRole::Topic; "for topic URI")]
+    #[test_case("//my-vehicle/A410/3/FFFE", UriRole::Topic; "for topic URI with max resource ID")]
+    #[test_case("//my-vehicle/A410/3/1", UriRole::RpcMethod; "for rpc method URI")]
+    #[test_case("//my-vehicle/A410/3/7FFF", UriRole::RpcMethod; "for rpc method URI with max resource ID")]
+    #[test_case("//my-vehicle/A410/3/0", UriRole::RpcResponse; "for rpc response URI")]
+    fn test_classify(uri: &str, expected_role: UriRole) {
+        let uuri = UUri::try_from(uri).expect("should have been able to create UUri");
+        assert_eq!(uuri.classify(), expected_role);
+    }
+
+    #[test]
+    fn test_distinct_authorities() {
+        let uris = [
+            UUri::try_from("/A410/3/1003").unwrap(),
+            UUri::try_from("//other-vehicle/A410/3/1003").unwrap(),
+            UUri::try_from("//my-cloud/A410/3/1005").unwrap(),
+            UUri::try_from("//*/A410/3/1005").unwrap(),
+            UUri::try_from("//other-vehicle/B5/1/0").unwrap(),
+            UUri {
+                authority_name: "MY-CLOUD".to_string(),
+                ue_id: 0xA410,
+                ue_version_major: 0x03,
+                resource_id: 0x1006,
+                ..Default::default()
+            },
+            UUri::try_from("/A410/3/1006").unwrap(),
+        ];
+        assert_eq!(
+            UUri::distinct_authorities(&uris),
+            vec!["other-vehicle", "my-cloud"]
+        );
+        assert!(UUri::distinct_authorities(&uris[..1]).is_empty());
+    }
+
+    #[test]
+    fn test_partition_local_remote() {
+        let uris: Vec<UUri> = [
+            "/A410/3/1003",
+            "//other-vehicle/A410/3/1003",
+            "//my-vehicle/A410/3/1004",
+            "//my-cloud/A410/3/1005",
+            "/A410/3/1006",
+        ]
+        .iter()
+        .map(|uri| UUri::try_from(*uri).expect("should have been able to create UUri"))
+        .collect();
+
+        let (local, remote) = UUri::partition_local_remote(&uris, "my-vehicle");
+        assert_eq!(local, vec![&uris[0], &uris[2], &uris[4]]);
+        assert_eq!(remote, vec![&uris[1], &uris[3]]);
+
+        let (local, remote) = UUri::partition_local_remote(&[], "my-vehicle");
+        assert!(local.is_empty());
+        assert!(remote.is_empty());
+    }
+
+    #[test_case("my-vehicle", "My-Vehicle"; "for mixed case local authority")]
+    #[test_case("MY-VEHICLE", "my-vehicle"; "for upper case authority name")]
+    #[test_case("my-vehicle", "my-vehicle"; "for identical authority names")]
+    fn test_is_local_to_ignores_case(authority_name: &str, local_authority: &str) {
+        let uri = UUri {
+            authority_name: authority_name.to_string(),
+            ue_id: 0xA410,
+            ue_version_major: 0x03,
+            resource_id: 0x1003,
+            ..Default::default()
+        };
+        let other_uri = UUri {
+            authority_name: "other-vehicle".to_string(),
+            ..uri.clone()
+        };
+        assert!(uri.is_local_to(local_authority));
+        assert!(!other_uri.is_local_to(local_authority));
+
+        let uris = [uri, other_uri];
+        let (local, remote) = UUri::partition_local_remote(&uris, local_authority);
+        assert_eq!(local, vec![&uris[0]]);
+        assert_eq!(remote, vec![&uris[1]]);
+    }
+
     // [utest->dsn~pattern-matching~1]
     #[test_case("//authority/A410/3/1003", "//authority/A410/3/1003"; "for identical URIs")]
     #[test_case("//*/A410/3/1003", "//authority/A410/3/1003"; "for pattern with wildcard authority")]
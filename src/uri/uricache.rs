@@ -0,0 +1,167 @@
+/********************************************************************************
+ * Copyright (c) 2024 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+use std::collections::VecDeque;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+use crate::{UUri, UUriError};
+
+/// A cache for [`UUri`]s that have been parsed from their string representation.
+///
+/// The cache retains the most recently used URIs up to a fixed capacity and evicts the
+/// least recently used URI when the capacity is exceeded. Lookups scan all cached entries,
+/// so the cache is intended to hold a small number of frequently used URIs, e.g. the topics
+/// that a service consumes messages from.
+pub struct UriCache {
+    capacity: usize,
+    // the least recently used entry is at the front
+    entries: Mutex<VecDeque<(String, UUri)>>,
+    hits: AtomicUsize,
+}
+
+impl UriCache {
+    /// Creates a new empty cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The maximum number of URIs to retain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is 0.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "Capacity must be greater than 0");
+        UriCache {
+            capacity,
+            entries: Mutex::new(VecDeque::with_capacity(capacity)),
+            hits: AtomicUsize::new(0),
+        }
+    }
+
+    /// Gets the UUri for a string, parsing the string only if it is not contained in the cache yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not contained in the cache and cannot be parsed into a UUri.
+    /// Strings that cannot be parsed are not added to the cache.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UriCache;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let cache = UriCache::new(16);
+    /// let uri = cache.get_or_parse("//my-vehicle/A410/3/9C01")?;
+    /// assert_eq!(uri.resource_id, 0x9C01);
+    /// assert_eq!(cache.hits(), 0);
+    ///
+    /// let cached_uri = cache.get_or_parse("//my-vehicle/A410/3/9C01")?;
+    /// assert_eq!(cached_uri, uri);
+    /// assert_eq!(cache.hits(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_or_parse(&self, uri: &str) -> Result<UUri, UUriError> {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(index) = entries.iter().position(|(key, _)| key == uri) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            if let Some(entry) = entries.remove(index) {
+                let cached_uri = entry.1.clone();
+                entries.push_back(entry);
+                return Ok(cached_uri);
+            }
+        }
+
+        let parsed_uri = UUri::from_str(uri)?;
+        if entries.len() >= self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back((uri.to_string(), parsed_uri.clone()));
+        Ok(parsed_uri)
+    }
+
+    /// Gets the number of times that a URI has been found in the cache.
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Gets the number of URIs contained in the cache.
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Checks if the cache does not contain any URIs.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOPIC_1: &str = "//my-vehicle/A410/3/9C01";
+    const TOPIC_2: &str = "//my-vehicle/A410/3/9C02";
+    const TOPIC_3: &str = "//my-vehicle/A410/3/9C03";
+
+    #[test]
+    fn test_get_or_parse_returns_cached_uri() {
+        let cache = UriCache::new(4);
+        let uri = cache.get_or_parse(TOPIC_1).unwrap();
+        assert_eq!(cache.hits(), 0);
+
+        assert_eq!(cache.get_or_parse(TOPIC_1).unwrap(), uri);
+        assert_eq!(cache.hits(), 1);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_get_or_parse_evicts_least_recently_used_uri() {
+        let cache = UriCache::new(2);
+        cache.get_or_parse(TOPIC_1).unwrap();
+        cache.get_or_parse(TOPIC_2).unwrap();
+        // make TOPIC_2 the least recently used entry
+        cache.get_or_parse(TOPIC_1).unwrap();
+        cache.get_or_parse(TOPIC_3).unwrap();
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.hits(), 1);
+
+        cache.get_or_parse(TOPIC_1).unwrap();
+        assert_eq!(cache.hits(), 2);
+        cache.get_or_parse(TOPIC_2).unwrap();
+        assert_eq!(cache.hits(), 2);
+    }
+
+    #[test]
+    fn test_get_or_parse_does_not_cache_invalid_uri() {
+        let cache = UriCache::new(2);
+        assert!(cache.get_or_parse("up://my-vehicle/A410").is_err());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_panics_for_zero_capacity() {
+        UriCache::new(0);
+    }
+}
@@ -13,6 +13,11 @@
 
 use std::error::Error;
 
+use protobuf::well_known_types::{
+    any::Any,
+    struct_::{Struct, Value},
+};
+
 pub use crate::up_core_api::ucode::UCode;
 pub use crate::up_core_api::ustatus::UStatus;
 
@@ -71,6 +76,61 @@ impl UStatus {
         }
     }
 
+    /// Creates a status representing a failure caused by invalid values of one or more fields.
+    ///
+    /// This can be used by RPC handlers to report all problems found in a request at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `errors` - The names of the fields containing invalid values along with a description
+    ///   of the problem.
+    ///
+    /// # Returns
+    ///
+    /// A status with code [`UCode::INVALID_ARGUMENT`], a message containing all of the problems
+    /// and a [`protobuf::well_known_types::struct_::Struct`] detail for each of the problems.
+    /// Each detail contains the properties `field` and `description`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{UCode, UStatus};
+    ///
+    /// let status = UStatus::invalid_argument_with_fields(&[
+    ///     ("ttl", "must be positive".to_string()),
+    ///     ("sink", "must be an RPC method".to_string()),
+    /// ]);
+    /// assert_eq!(status.get_code(), UCode::INVALID_ARGUMENT);
+    /// assert_eq!(status.get_message(), "ttl: must be positive; sink: must be an RPC method");
+    /// assert_eq!(status.details.len(), 2);
+    /// ```
+    pub fn invalid_argument_with_fields(errors: &[(&str, String)]) -> Self {
+        let details = errors
+            .iter()
+            .map(|(field, description)| {
+                let mut detail = Struct::new();
+                for (key, value) in [("field", *field), ("description", description.as_str())] {
+                    let mut property = Value::new();
+                    property.set_string_value(value.to_string());
+                    detail.fields.insert(key.to_string(), property);
+                }
+                // serializing a Struct that only contains string values cannot fail
+                Any::pack(&detail).expect("should have been able to pack field details")
+            })
+            .collect();
+        let message = errors
+            .iter()
+            .map(|(field, description)| format!("{field}: {description}"))
+            .collect::<Vec<_>>()
+            .join("; ");
+        UStatus {
+            code: UCode::INVALID_ARGUMENT.into(),
+            message: Some(message),
+            details,
+            ..Default::default()
+        }
+    }
+
     /// Checks if this status represents a failure.
     ///
     /// # Examples
@@ -154,6 +214,33 @@ impl UStatus {
 
 impl Error for UStatus {}
 
+impl UCode {
+    /// Checks if an operation that failed with this code may succeed when being retried.
+    ///
+    /// # Returns
+    ///
+    /// `true` for [`UCode::UNAVAILABLE`], [`UCode::DEADLINE_EXCEEDED`], [`UCode::ABORTED`] and
+    /// [`UCode::RESOURCE_EXHAUSTED`], `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UCode;
+    ///
+    /// assert!(UCode::UNAVAILABLE.is_retryable());
+    /// assert!(!UCode::INVALID_ARGUMENT.is_retryable());
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            UCode::UNAVAILABLE
+                | UCode::DEADLINE_EXCEEDED
+                | UCode::ABORTED
+                | UCode::RESOURCE_EXHAUSTED
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,4 +276,37 @@ mod tests {
             assert_eq!(ustatus.is_success(), *code == UCode::OK);
         });
     }
+
+    #[test]
+    fn test_invalid_argument_with_fields() {
+        let status = UStatus::invalid_argument_with_fields(&[
+            ("ttl", "must be positive".to_string()),
+            ("priority", "must be at least CS4".to_string()),
+            ("sink", "must be an RPC method".to_string()),
+        ]);
+        assert_eq!(status.get_code(), UCode::INVALID_ARGUMENT);
+        assert_eq!(status.details.len(), 3);
+
+        let detail = status.details[1].unpack::<Struct>().unwrap().unwrap();
+        assert_eq!(detail.fields["field"].string_value(), "priority");
+        assert_eq!(
+            detail.fields["description"].string_value(),
+            "must be at least CS4"
+        );
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        let retryable_codes = [
+            UCode::UNAVAILABLE,
+            UCode::DEADLINE_EXCEEDED,
+            UCode::ABORTED,
+            UCode::RESOURCE_EXHAUSTED,
+        ];
+        UCode::VALUES.iter().for_each(|code| {
+            assert_eq!(code.is_retryable(), retryable_codes.contains(code));
+        });
+        assert!(!UCode::INVALID_ARGUMENT.is_retryable());
+        assert!(!UCode::NOT_FOUND.is_retryable());
+    }
 }
@@ -11,7 +11,7 @@
  * SPDX-License-Identifier: Apache-2.0
  ********************************************************************************/
 
-use std::{hash::Hash, str::FromStr};
+use std::{cmp::Ordering, hash::Hash, str::FromStr};
 
 pub use crate::up_core_api::uuid::UUID;
 
@@ -21,6 +21,7 @@ pub use uuidbuilder::UUIDBuilder;
 
 const BITMASK_VERSION: u64 = 0b1111 << 12;
 pub(crate) const VERSION_CUSTOM: u64 = 0b1000 << 12;
+const BITMASK_COUNTER: u64 = 0x0fff;
 const BITMASK_VARIANT: u64 = 0b11 << 62;
 pub(crate) const VARIANT_RFC4122: u64 = 0b10 << 62;
 
@@ -95,6 +96,58 @@ impl UUID {
         })
     }
 
+    /// Creates a new uProtocol UUID from its individual components.
+    ///
+    /// The version and variant identifiers are set as required by the
+    /// [uProtocol specification](https://github.com/eclipse-uprotocol/up-spec/blob/main/basics/uuid.adoc).
+    /// This allows creating UUIDs with well-known values, e.g. in tests, without using a [`UUIDBuilder`].
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp_ms` - The (48 bit) number of milliseconds since UNIX Epoch.
+    /// * `counter` - The (12 bit) counter.
+    /// * `random` - The (62 bit) random portion.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the components exceeds its number of bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUID;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let uuid = UUID::try_from_fields(0x018D548EA8E0, 0x0A5, 0x0010101010101a1a)?;
+    /// assert!(uuid.is_uprotocol_uuid());
+    /// assert_eq!(uuid.get_time(), Some(0x018D548EA8E0));
+    /// assert_eq!(uuid.get_counter(), Some(0x0A5));
+    /// assert_eq!(uuid.random_bits(), Some(0x0010101010101a1a));
+    ///
+    /// assert!(UUID::try_from_fields(0x1_0000_0000_0000, 0, 0).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_fields(
+        timestamp_ms: u64,
+        counter: u16,
+        random: u64,
+    ) -> Result<Self, UuidConversionError> {
+        if timestamp_ms >> 48 != 0 {
+            return Err(UuidConversionError::new("timestamp exceeds 48 bits"));
+        }
+        if u64::from(counter) & !BITMASK_COUNTER != 0 {
+            return Err(UuidConversionError::new("counter exceeds 12 bits"));
+        }
+        if random & BITMASK_VARIANT != 0 {
+            return Err(UuidConversionError::new("random portion exceeds 62 bits"));
+        }
+        Self::from_u64_pair(
+            (timestamp_ms << 16) | VERSION_CUSTOM | u64::from(counter),
+            VARIANT_RFC4122 | random,
+        )
+    }
+
     /// Serializes this UUID to a hyphenated string as defined by
     /// [RFC 4122, Section 3](https://www.rfc-editor.org/rfc/rfc4122.html#section-3)
     /// using lower case characters.
@@ -165,6 +218,68 @@ impl UUID {
         }
     }
 
+    /// Returns the counter portion of this UUID.
+    ///
+    /// The counter distinguishes UUIDs that have been created within the same millisecond.
+    ///
+    /// # Returns
+    ///
+    /// The 12 bit counter if this UUID is a uProtocol UUID, or [`Option::None`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUID;
+    ///
+    /// // timestamp = 1, ver = 0b1000, counter = 0x0A5
+    /// let msb = 0x00000000000180A5_u64;
+    /// // variant = 0b10
+    /// let lsb = 0x8000000000000000_u64;
+    /// let uuid = UUID { msb, lsb, ..Default::default() };
+    /// assert_eq!(uuid.get_counter(), Some(0x0A5));
+    /// ```
+    pub fn get_counter(&self) -> Option<u16> {
+        if self.is_uprotocol_uuid() {
+            // the counter is contained in the 12 least significant bits of the msb
+            Some((self.msb & BITMASK_COUNTER) as u16)
+        } else {
+            None
+        }
+    }
+
+    /// Determines the earliest and the latest creation time of a set of UUIDs.
+    ///
+    /// UUIDs that are not uProtocol UUIDs are ignored.
+    ///
+    /// # Returns
+    ///
+    /// The minimum and maximum number of milliseconds since UNIX EPOCH, or [`Option::None`]
+    /// if the given UUIDs do not contain any uProtocol UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUID;
+    ///
+    /// let uuids = [
+    ///     // timestamp = 2, ver = 0b1000, variant = 0b10
+    ///     UUID { msb: 0x0000000000028000_u64, lsb: 0x8000000000000000_u64, ..Default::default() },
+    ///     // timestamp = 1, ver = 0b1000, variant = 0b10
+    ///     UUID { msb: 0x0000000000018000_u64, lsb: 0x8000000000000000_u64, ..Default::default() },
+    /// ];
+    /// assert_eq!(UUID::time_range(&uuids), Some((1, 2)));
+    /// assert_eq!(UUID::time_range(&[]), None);
+    /// ```
+    pub fn time_range(uuids: &[UUID]) -> Option<(u64, u64)> {
+        uuids
+            .iter()
+            .filter_map(UUID::get_time)
+            .fold(None, |range, time| match range {
+                None => Some((time, time)),
+                Some((min, max)) => Some((min.min(time), max.max(time))),
+            })
+    }
+
     /// Checks if this is a valid uProtocol UUID.
     ///
     /// # Returns
@@ -198,6 +313,185 @@ impl UUID {
     pub fn is_uprotocol_uuid(&self) -> bool {
         self.is_custom_version() && self.is_rfc_variant()
     }
+
+    /// Returns the random portion of this UUID.
+    ///
+    /// The random portion consists of the 62 least significant bits of the UUID, i.e. all bits of the
+    /// least significant 8 bytes except for the two most significant variant bits. Note that all UUIDs
+    /// created by [`UUIDBuilder::build`] within the same process share the same random portion.
+    ///
+    /// # Returns
+    ///
+    /// The random bits if this UUID is a uProtocol UUID, or [`Option::None`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUID;
+    ///
+    /// // timestamp = 1, ver = 0b1000
+    /// let msb = 0x0000000000018000_u64;
+    /// // variant = 0b10, random = 0x0010101010101a1a
+    /// let lsb = 0x8010101010101a1a_u64;
+    /// let uuid = UUID { msb, lsb, ..Default::default() };
+    /// assert_eq!(uuid.random_bits(), Some(0x0010101010101a1a_u64));
+    /// ```
+    pub fn random_bits(&self) -> Option<u64> {
+        if self.is_uprotocol_uuid() {
+            Some(self.lsb & !BITMASK_VARIANT)
+        } else {
+            None
+        }
+    }
+
+    /// Gets this UUID's bytes for use as a trace ID, e.g. in an OpenTelemetry trace context.
+    ///
+    /// # Returns
+    ///
+    /// The 16 bytes of this UUID in big-endian order, i.e. in the same order as in the
+    /// UUID's [hyphenated string representation](`UUID::to_hyphenated_string`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUID;
+    ///
+    /// // timestamp = 1, ver = 0b1000
+    /// let msb = 0x0000000000018000_u64;
+    /// // variant = 0b10, random = 0x0010101010101a1a
+    /// let lsb = 0x8010101010101a1a_u64;
+    /// let uuid = UUID { msb, lsb, ..Default::default() };
+    /// assert_eq!(
+    ///     uuid.as_trace_id(),
+    ///     [0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x80, 0x00, 0x80, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1a, 0x1a]
+    /// );
+    /// ```
+    pub fn as_trace_id(&self) -> [u8; 16] {
+        let mut trace_id = [0_u8; 16];
+        trace_id[..8].copy_from_slice(&self.msb.to_be_bytes());
+        trace_id[8..].copy_from_slice(&self.lsb.to_be_bytes());
+        trace_id
+    }
+
+    /// Derives a span ID from this UUID, e.g. for use in an OpenTelemetry trace context.
+    ///
+    /// The span ID consists of the [random bits](`UUID::random_bits`) combined (XOR) with the
+    /// timestamp and counter. UUIDs created by the same [`UUIDBuilder`] share the same random bits
+    /// but differ in timestamp and/or counter, so they result in different span IDs.
+    ///
+    /// # Returns
+    ///
+    /// The span ID's 8 bytes in big-endian order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUIDBuilder;
+    ///
+    /// let uuid = UUIDBuilder::build();
+    /// let other_uuid = UUIDBuilder::build();
+    /// assert_ne!(uuid.as_span_id(), other_uuid.as_span_id());
+    /// ```
+    pub fn as_span_id(&self) -> [u8; 8] {
+        let span_id = (self.lsb & !BITMASK_VARIANT) ^ (self.msb & !BITMASK_VERSION);
+        span_id.to_be_bytes()
+    }
+
+    /// Compares two UUIDs by their creation time.
+    ///
+    /// This is useful for sorting messages by the point in time at which they have been created,
+    /// e.g. `uuids.sort_by(UUID::compare_by_time)`.
+    ///
+    /// uProtocol UUIDs are ordered by their timestamp first and then by their counter and random bits.
+    /// UUIDs that are not uProtocol UUIDs are ordered before all uProtocol UUIDs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUID;
+    ///
+    /// // timestamp = 1, ver = 0b1000, variant = 0b10
+    /// let earlier = UUID { msb: 0x0000000000018000_u64, lsb: 0x8010101010101a1a_u64, ..Default::default() };
+    /// // timestamp = 2, ver = 0b1000, variant = 0b10
+    /// let later = UUID { msb: 0x0000000000028000_u64, lsb: 0x8000000000000000_u64, ..Default::default() };
+    /// let mut uuids = vec![later.clone(), earlier.clone()];
+    /// uuids.sort_by(UUID::compare_by_time);
+    /// assert_eq!(uuids, vec![earlier, later]);
+    /// ```
+    pub fn compare_by_time(a: &UUID, b: &UUID) -> Ordering {
+        match (a.get_time(), b.get_time()) {
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            // for uProtocol UUIDs the timestamp is followed by the counter
+            _ => (a.msb, a.lsb).cmp(&(b.msb, b.lsb)),
+        }
+    }
+
+    /// Checks if two strings represent the same UUID.
+    ///
+    /// The strings are compared case-insensitively and hyphens are ignored. This avoids
+    /// parsing (and allocating) UUIDs when only their string representations are at hand,
+    /// e.g. when using them as keys of a map. Note that this function does not check
+    /// if the strings represent valid UUIDs at all.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUID;
+    ///
+    /// assert!(UUID::str_eq(
+    ///     "00000000-0001-8000-8010-101010101a1a",
+    ///     "00000000000180008010101010101A1A"
+    /// ));
+    /// assert!(!UUID::str_eq(
+    ///     "00000000-0001-8000-8010-101010101a1a",
+    ///     "00000000-0001-8000-8010-101010101a1b"
+    /// ));
+    /// ```
+    pub fn str_eq(a: &str, b: &str) -> bool {
+        let a_digits = a
+            .bytes()
+            .filter(|c| *c != b'-')
+            .map(|c| c.to_ascii_lowercase());
+        let b_digits = b
+            .bytes()
+            .filter(|c| *c != b'-')
+            .map(|c| c.to_ascii_lowercase());
+        a_digits.eq(b_digits)
+    }
+
+    /// Creates a uProtocol UUID from this UUID by overwriting its version and variant identifiers.
+    ///
+    /// All other bits are preserved. Note that this is a lossy repair which is only intended to support
+    /// interoperability with systems that do not (yet) create proper uProtocol UUIDs. In particular, the
+    /// resulting UUID's timestamp and counter are only meaningful if the original UUID's most significant
+    /// bits already contained a timestamp as defined by the
+    /// [uProtocol spec](https://github.com/eclipse-uprotocol/uprotocol-spec).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUID;
+    ///
+    /// // timestamp = 1, (invalid) ver = 0b0100
+    /// let msb = 0x0000000000014000u64;
+    /// // (invalid) variant = 0b11, random = 0x0010101010101a1a
+    /// let lsb = 0xC010101010101a1au64;
+    /// let uuid = UUID { msb, lsb, ..Default::default() };
+    /// assert!(!uuid.is_uprotocol_uuid());
+    ///
+    /// let repaired_uuid = uuid.repair_to_uprotocol();
+    /// assert!(repaired_uuid.is_uprotocol_uuid());
+    /// assert_eq!(repaired_uuid.get_time(), Some(0x1_u64));
+    /// assert_eq!(repaired_uuid.lsb, 0x8010101010101a1au64);
+    /// ```
+    pub fn repair_to_uprotocol(&self) -> UUID {
+        UUID {
+            msb: (self.msb & !BITMASK_VERSION) | VERSION_CUSTOM,
+            lsb: (self.lsb & !BITMASK_VARIANT) | VARIANT_RFC4122,
+            ..Default::default()
+        }
+    }
 }
 
 impl Eq for UUID {}
@@ -265,6 +559,8 @@ impl FromStr for UUID {
 
 #[cfg(test)]
 mod tests {
+    use test_case::test_case;
+
     use super::*;
 
     #[test]
@@ -299,4 +595,195 @@ mod tests {
         assert!(uuid.is_uprotocol_uuid());
         assert_eq!(uuid.get_time(), Some(0x1_u64));
     }
+
+    #[test_case("00000000-0001-8000-8010-101010101a1a", "00000000-0001-8000-8010-101010101a1a", true; "for identical strings")]
+    #[test_case("00000000-0001-8000-8010-101010101a1a", "00000000-0001-8000-8010-101010101A1A", true; "for different case")]
+    #[test_case("00000000-0001-8000-8010-101010101a1a", "00000000000180008010101010101a1a", true; "for missing hyphens")]
+    #[test_case("00000000-0001-8000-8010-101010101a1a", "00000000000180008010101010101A1A", true; "for different case and missing hyphens")]
+    #[test_case("00000000-0001-8000-8010-101010101a1a", "00000000-0001-8000-8010-101010101a1b", false; "for different UUIDs")]
+    #[test_case("00000000-0001-8000-8010-101010101a1a", "00000000-0001-8000-8010-101010101a1", false; "for prefix")]
+    fn test_str_eq(a: &str, b: &str, expected_result: bool) {
+        assert_eq!(UUID::str_eq(a, b), expected_result);
+        assert_eq!(UUID::str_eq(b, a), expected_result);
+    }
+
+    #[test]
+    fn test_random_bits_differ_between_builders() {
+        let uuid_one = UUIDBuilder::new().build_internal();
+        let uuid_two = UUIDBuilder::new().build_internal();
+        assert!(uuid_one.random_bits().is_some());
+        assert!(uuid_two.random_bits().is_some());
+        assert_ne!(uuid_one.random_bits(), uuid_two.random_bits());
+
+        let invalid_uuid = UUID {
+            msb: 0x000000000001C000_u64,
+            lsb: 0x8000000000000000_u64,
+            ..Default::default()
+        };
+        assert!(invalid_uuid.random_bits().is_none());
+    }
+
+    #[test]
+    fn test_as_trace_id_equals_uuid_bytes() {
+        let uuid = UUIDBuilder::build();
+        let trace_id = uuid.as_trace_id();
+        assert_eq!(UUID::from_bytes(&trace_id).unwrap(), uuid);
+
+        let hex_trace_id = trace_id
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<String>();
+        assert!(UUID::str_eq(&hex_trace_id, &uuid.to_hyphenated_string()));
+    }
+
+    #[test]
+    fn test_as_span_id_differs_for_different_uuids() {
+        let builder = UUIDBuilder::seeded(7);
+        let uuids = [
+            builder.build_with_instant(0x018D548EA8E0).unwrap(),
+            builder.build_with_instant(0x018D548EA8E0).unwrap(),
+            builder.build_with_instant(0x018D548EA8E1).unwrap(),
+            UUIDBuilder::seeded(8)
+                .build_with_instant(0x018D548EA8E1)
+                .unwrap(),
+        ];
+        let span_ids = uuids
+            .iter()
+            .map(UUID::as_span_id)
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(span_ids.len(), uuids.len());
+    }
+
+    #[test]
+    fn test_compare_by_time_orders_chronologically() {
+        let builder = UUIDBuilder::seeded(1);
+        let other_builder = UUIDBuilder::seeded(2);
+        let chronological_uuids = vec![
+            builder.build_with_instant(0x018D548EA8E0).unwrap(),
+            builder.build_with_instant(0x018D548EA8E0).unwrap(),
+            other_builder.build_with_instant(0x018D548EA8E1).unwrap(),
+            other_builder.build_with_instant(0x018D548EA8E5).unwrap(),
+            other_builder.build_with_instant(0x018D548EA8E5).unwrap(),
+            other_builder.build_with_instant(0x018D548EA8F3).unwrap(),
+        ];
+        let invalid_uuid = UUID {
+            msb: 0xFFFFFFFFFFFFC000_u64,
+            lsb: 0x8000000000000000_u64,
+            ..Default::default()
+        };
+
+        let mut uuids = chronological_uuids.clone();
+        uuids.reverse();
+        uuids.insert(2, invalid_uuid.clone());
+        uuids.sort_by(UUID::compare_by_time);
+
+        assert_eq!(uuids[0], invalid_uuid);
+        assert_eq!(uuids[1..], chronological_uuids[..]);
+    }
+
+    #[test]
+    fn test_get_counter_increments_for_same_instant() {
+        let builder = UUIDBuilder::seeded(3);
+        let counters = (0..5)
+            .map(|_| {
+                builder
+                    .build_with_instant(0x018D548EA8E0)
+                    .unwrap()
+                    .get_counter()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(counters, vec![Some(0), Some(1), Some(2), Some(3), Some(4)]);
+        assert_eq!(
+            builder
+                .build_with_instant(0x018D548EA8E1)
+                .unwrap()
+                .get_counter(),
+            Some(0)
+        );
+
+        let invalid_uuid = UUID {
+            msb: 0x000000000001C0A5_u64,
+            lsb: 0x8000000000000000_u64,
+            ..Default::default()
+        };
+        assert!(invalid_uuid.get_counter().is_none());
+    }
+
+    #[test_case(0x0000_0000_0000, 0x000, 0x0000_0000_0000_0000; "for minimum values")]
+    #[test_case(0x018D_548E_A8E0, 0x0A5, 0x0010_1010_1010_1A1A; "for arbitrary values")]
+    #[test_case(0xFFFF_FFFF_FFFF, 0xFFF, 0x3FFF_FFFF_FFFF_FFFF; "for maximum values")]
+    fn test_try_from_fields_sets_version_and_variant(timestamp_ms: u64, counter: u16, random: u64) {
+        let uuid = UUID::try_from_fields(timestamp_ms, counter, random).unwrap();
+        let hyphenated_uuid = uuid.to_hyphenated_string();
+        // the version nibble is the first digit of the third group
+        assert_eq!(&hyphenated_uuid[14..15], "8");
+        // the variant bits are the two most significant bits of the fourth group
+        let variant_nibble = u8::from_str_radix(&hyphenated_uuid[19..20], 16).unwrap();
+        assert_eq!(variant_nibble >> 2, 0b10);
+
+        assert_eq!(uuid.get_time(), Some(timestamp_ms));
+        assert_eq!(uuid.get_counter(), Some(counter));
+        assert_eq!(uuid.random_bits(), Some(random));
+    }
+
+    #[test_case(0x1_0000_0000_0000, 0x000, 0x0; "for timestamp exceeding 48 bits")]
+    #[test_case(0x0, 0x1000, 0x0; "for counter exceeding 12 bits")]
+    #[test_case(0x0, 0x000, 0x4000_0000_0000_0000; "for random exceeding 62 bits")]
+    fn test_try_from_fields_fails_for_invalid_values(timestamp_ms: u64, counter: u16, random: u64) {
+        assert!(UUID::try_from_fields(timestamp_ms, counter, random).is_err());
+    }
+
+    #[test]
+    fn test_time_range() {
+        let uuid_for_time = |time: u64| UUID {
+            msb: (time << 16) | VERSION_CUSTOM,
+            lsb: VARIANT_RFC4122,
+            ..Default::default()
+        };
+        let invalid_uuid = UUID {
+            msb: 0x000000000001C000_u64,
+            lsb: 0x8000000000000000_u64,
+            ..Default::default()
+        };
+        let uuids = vec![
+            uuid_for_time(0x018D548EA8E5),
+            invalid_uuid.clone(),
+            uuid_for_time(0x018D548EA8E0),
+            uuid_for_time(0x018D548EA8F3),
+            uuid_for_time(0x018D548EA8E1),
+        ];
+        assert_eq!(
+            UUID::time_range(&uuids),
+            Some((0x018D548EA8E0, 0x018D548EA8F3))
+        );
+        assert_eq!(
+            UUID::time_range(&uuids[..1]),
+            Some((0x018D548EA8E5, 0x018D548EA8E5))
+        );
+        assert_eq!(UUID::time_range(&[invalid_uuid]), None);
+    }
+
+    #[test]
+    fn test_repair_to_uprotocol_preserves_random_bits() {
+        // ver = 0b0100, variant = 0b11
+        let v4_uuid = UUID {
+            msb: 0xa1a2a3a4b1b24c1c_u64,
+            lsb: 0xdd1de1e2e3e4e5e6_u64,
+            ..Default::default()
+        };
+        assert!(!v4_uuid.is_uprotocol_uuid());
+
+        let repaired_uuid = v4_uuid.repair_to_uprotocol();
+        assert!(repaired_uuid.is_uprotocol_uuid());
+        assert_eq!(
+            repaired_uuid.msb & !BITMASK_VERSION,
+            v4_uuid.msb & !BITMASK_VERSION
+        );
+        assert_eq!(
+            repaired_uuid.lsb & !BITMASK_VARIANT,
+            v4_uuid.lsb & !BITMASK_VARIANT
+        );
+        // repairing a valid uProtocol UUID does not change it
+        assert_eq!(repaired_uuid.repair_to_uprotocol(), repaired_uuid);
+    }
 }
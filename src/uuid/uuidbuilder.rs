@@ -12,10 +12,12 @@
  ********************************************************************************/
 
 use once_cell::sync::Lazy;
-use rand::random;
+use rand::rngs::StdRng;
+use rand::{thread_rng, RngCore, SeedableRng};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::uuid::UuidConversionError;
 use crate::UUID;
 
 const BITMASK_CLEAR_VERSION: u64 = 0xffff_ffff_ffff_0fff;
@@ -53,9 +55,128 @@ impl UUIDBuilder {
     ///
     /// For internal testing purposes only. For end-users, please use [`UUIDBuilder::build()`]
     pub(crate) fn new() -> Self {
+        Self::with_rng(|| thread_rng().next_u64())
+    }
+
+    /// Creates a new builder that uses a specific source of randomness.
+    ///
+    /// The source is invoked once for determining the `rand_b` portion of all UUIDs created by
+    /// the builder. This allows using a particular CSPRNG, e.g. in environments that require
+    /// certified algorithms, or a seeded generator for reproducible UUIDs.
+    /// [`UUIDBuilder::build()`] uses the thread-local random number generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `next_random` - A function returning a random 64 bit value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rand::{rngs::StdRng, RngCore, SeedableRng};
+    /// use up_rust::UUIDBuilder;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let mut other_rng = StdRng::seed_from_u64(42);
+    /// let builder = UUIDBuilder::with_rng(|| rng.next_u64());
+    /// let other_builder = UUIDBuilder::with_rng(|| other_rng.next_u64());
+    /// assert_eq!(
+    ///     builder.build_with_instant(1_700_000_000_000)?.random_bits(),
+    ///     other_builder.build_with_instant(1_700_000_000_000)?.random_bits()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_rng<F: FnOnce() -> u64>(next_random: F) -> Self {
         UUIDBuilder {
             msb: AtomicU64::new(0),
-            lsb: random::<u64>() & BITMASK_CLEAR_VARIANT | crate::uuid::VARIANT_RFC4122,
+            lsb: next_random() & BITMASK_CLEAR_VARIANT | crate::uuid::VARIANT_RFC4122,
+        }
+    }
+
+    /// Creates a new builder that creates reproducible UUIDs.
+    ///
+    /// The `rand_b` portion of all UUIDs created by the builder is derived from the given seed.
+    /// Together with [`UUIDBuilder::build_with_instant`], this allows tests to create the same
+    /// UUIDs on each run, e.g. for comparing them to snapshots.
+    ///
+    /// Production code should use [`UUIDBuilder::build()`] instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The value to derive the `rand_b` portion from.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUIDBuilder;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let uuid = UUIDBuilder::seeded(42).build_with_instant(1_700_000_000_000)?;
+    /// assert_eq!(uuid, UUIDBuilder::seeded(42).build_with_instant(1_700_000_000_000)?);
+    /// assert_ne!(uuid, UUIDBuilder::seeded(43).build_with_instant(1_700_000_000_000)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn seeded(seed: u64) -> Self {
+        Self::with_rng(|| StdRng::seed_from_u64(seed).next_u64())
+    }
+
+    /// Creates a new UUID for a given point in time.
+    ///
+    /// UUIDs created for the same point in time have increasing counter values, starting at 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `unix_millis` - The point in time as the number of milliseconds since UNIX Epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    /// * the point in time does not fit into 48 bits, or
+    /// * the point in time is earlier than the one used for the previously created UUID, or
+    /// * 4096 UUIDs have already been created for the same point in time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::UUIDBuilder;
+    ///
+    /// let builder = UUIDBuilder::seeded(42);
+    /// let uuid = builder.build_with_instant(1_700_000_000_001).unwrap();
+    /// assert_eq!(uuid.get_time(), Some(1_700_000_000_001));
+    /// assert!(builder.build_with_instant(1_700_000_000_000).is_err());
+    /// ```
+    pub fn build_with_instant(&self, unix_millis: u64) -> Result<UUID, UuidConversionError> {
+        if unix_millis >> 48 != 0 {
+            return Err(UuidConversionError::new(
+                "point in time must fit into 48 bits",
+            ));
+        }
+        loop {
+            let current_msb = self.msb.load(Ordering::SeqCst);
+            let current_timestamp = current_msb >> 16;
+            let new_msb = if current_msb == 0 || unix_millis > current_timestamp {
+                (unix_millis << 16) & BITMASK_CLEAR_VERSION | crate::uuid::VERSION_CUSTOM
+            } else if unix_millis < current_timestamp {
+                return Err(UuidConversionError::new(
+                    "point in time must not be earlier than that of the previously created UUID",
+                ));
+            } else if current_msb & MAX_COUNT < MAX_COUNT {
+                current_msb + 1
+            } else {
+                return Err(UuidConversionError::new(
+                    "cannot create more than 4096 UUIDs for the same point in time",
+                ));
+            };
+
+            if self
+                .msb
+                .compare_exchange(current_msb, new_msb, Ordering::SeqCst, Ordering::SeqCst)
+                .is_ok()
+            {
+                return UUID::from_u64_pair(new_msb, self.lsb);
+            }
         }
     }
 
@@ -131,6 +252,89 @@ mod tests {
     use async_std::task;
     use std::collections::HashSet;
 
+    #[test]
+    fn test_seeded_builders_create_identical_uuids() {
+        let instant = 0x0000_018F_1234_5678;
+        let builder = UUIDBuilder::seeded(0xABCD);
+        let other_builder = UUIDBuilder::seeded(0xABCD);
+
+        let first_uuid = builder.build_with_instant(instant).unwrap();
+        let second_uuid = builder.build_with_instant(instant).unwrap();
+        assert_eq!(
+            first_uuid,
+            other_builder.build_with_instant(instant).unwrap()
+        );
+        assert_eq!(
+            second_uuid,
+            other_builder.build_with_instant(instant).unwrap()
+        );
+        assert_ne!(first_uuid, second_uuid);
+
+        assert!(first_uuid.is_uprotocol_uuid());
+        assert_eq!(first_uuid.get_time(), Some(instant));
+        assert_eq!(first_uuid.msb & MAX_COUNT, 0);
+        assert_eq!(second_uuid.msb & MAX_COUNT, 1);
+    }
+
+    #[test]
+    fn test_seeded_builders_with_different_seeds_create_different_uuids() {
+        let instant = 0x0000_018F_1234_5678;
+        assert_ne!(
+            UUIDBuilder::seeded(1).build_with_instant(instant).unwrap(),
+            UUIDBuilder::seeded(2).build_with_instant(instant).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_build_with_instant_fails_for_earlier_instant() {
+        let instant = 0x0000_018F_1234_5678;
+        let builder = UUIDBuilder::seeded(0xABCD);
+        assert!(builder.build_with_instant(instant).is_ok());
+        assert!(builder.build_with_instant(instant - 1).is_err());
+        assert!(builder.build_with_instant(instant).is_ok());
+        assert!(builder.build_with_instant(instant + 1).is_ok());
+    }
+
+    #[test]
+    fn test_build_with_instant_fails_for_counter_overflow() {
+        let instant = 0x0000_018F_1234_5678;
+        let builder = UUIDBuilder::seeded(0xABCD);
+        for counter in 0..=MAX_COUNT {
+            let uuid = builder.build_with_instant(instant).unwrap();
+            assert_eq!(uuid.msb & MAX_COUNT, counter);
+        }
+        assert!(builder.build_with_instant(instant).is_err());
+        let uuid = builder.build_with_instant(instant + 1).unwrap();
+        assert_eq!(uuid.msb & MAX_COUNT, 0);
+    }
+
+    #[test]
+    fn test_build_with_instant_fails_for_instant_exceeding_48_bits() {
+        let builder = UUIDBuilder::seeded(0xABCD);
+        assert!(builder.build_with_instant(1 << 48).is_err());
+    }
+
+    #[test]
+    fn test_with_rng_creates_reproducible_random_bits() {
+        let rng = StdRng::seed_from_u64(0x1234);
+        let expected_random_bits = rng.clone().next_u64() & BITMASK_CLEAR_VARIANT;
+
+        let mut first_rng = rng.clone();
+        let mut second_rng = rng;
+        let uuid = UUIDBuilder::with_rng(|| first_rng.next_u64()).build_internal();
+        let other_uuid = UUIDBuilder::with_rng(|| second_rng.next_u64()).build_internal();
+        assert_eq!(uuid.random_bits(), Some(expected_random_bits));
+        assert_eq!(other_uuid.random_bits(), Some(expected_random_bits));
+
+        let mut other_rng = StdRng::seed_from_u64(0x5678);
+        assert_ne!(
+            UUIDBuilder::with_rng(|| other_rng.next_u64())
+                .build_internal()
+                .random_bits(),
+            Some(expected_random_bits)
+        );
+    }
+
     #[async_std::test]
     async fn test_uuidbuilder_concurrency_safety_with_lsb_check() {
         // create enough UUIDs / task that we're likely to run over the counter